@@ -1,5 +1,9 @@
 fn main() {
-    match sdsl::build() {
+    // Types used only through aliases or in dependent crates must be listed explicitly.
+    let specs = [sdsl::spec!(
+        sdsl::bit_vectors::RrrVector<sdsl::int_vectors::IntVector<0>, 63, 32>
+    )];
+    match sdsl::Build::new().specs(&specs).opt_level(3).compile() {
         Ok(_) => {}
         Err(e) => panic!("Error: {}", e),
     };
@@ -24,7 +24,7 @@ fn test_len_gives_number_of_elements() -> Result<()> {
 #[test]
 fn test_correct_len_after_resize() -> Result<()> {
     let mut bv = sdsl::bit_vectors::BitVector::new(5, 1)?;
-    bv.resize(6);
+    bv.resize(6)?;
 
     let result = bv.len();
     let expected = 6;
@@ -35,7 +35,7 @@ fn test_correct_len_after_resize() -> Result<()> {
 #[test]
 fn test_resize_truncates_vector() -> Result<()> {
     let mut bv = sdsl::bit_vector! {1, 0, 1};
-    bv.resize(2);
+    bv.resize(2)?;
 
     let result: Vec<_> = bv.iter().collect();
     let expected = vec![1, 0];
@@ -112,3 +112,223 @@ fn test_into_iter() -> Result<()> {
     assert_eq!(result, expected);
     Ok(())
 }
+
+#[test]
+fn test_get_range() -> Result<()> {
+    let bv = sdsl::bit_vector! {1, 1, 0, 1};
+    let mut values = [0; 3];
+    bv.get_range(1, &mut values);
+
+    let result = values;
+    let expected = [1, 0, 1];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_set_range() -> Result<()> {
+    let mut bv = sdsl::bit_vector! {1, 1, 0, 1};
+    bv.set_range(0, &[0, 0]);
+
+    let result: Vec<_> = bv.iter().collect();
+    let expected = vec![0, 0, 0, 1];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_collect_from_iterator() -> Result<()> {
+    let bv: sdsl::bit_vectors::BitVector = vec![true, false, true].into_iter().collect();
+
+    let result: Vec<_> = bv.iter().collect();
+    let expected = vec![1, 0, 1];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_from_vec() -> Result<()> {
+    let bv = sdsl::bit_vectors::BitVector::from(vec![true, true, false, true]);
+
+    let result = bv;
+    let expected = sdsl::bit_vector! {1, 1, 0, 1};
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_extend() -> Result<()> {
+    let mut bv = sdsl::bit_vector! {1, 0};
+    bv.extend(vec![false, true]);
+
+    let result: Vec<_> = bv.iter().collect();
+    let expected = vec![1, 0, 0, 1];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_try_get() -> Result<()> {
+    let bv = sdsl::bit_vector! {1, 0};
+
+    let result = (bv.try_get(0), bv.try_get(2));
+    let expected = (
+        Ok(1),
+        Err(sdsl::Error::IndexOutOfBounds { index: 2, len: 2 }),
+    );
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_index() -> Result<()> {
+    let bv = sdsl::bit_vector! {1, 0, 1};
+
+    let result = (bv[0], bv[1], bv[2]);
+    let expected = (true, false, true);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_slice() -> Result<()> {
+    let bv = sdsl::bit_vector! {1, 0, 1, 1};
+
+    let result = bv.slice(1..4).to_vec();
+    let expected = vec![0, 1, 1];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_bitwise_operators() -> Result<()> {
+    let a = sdsl::bit_vector! {1, 1, 0, 0};
+    let b = sdsl::bit_vector! {1, 0, 1, 0};
+
+    let result = (&a & &b, &a | &b, &a ^ &b, !&a);
+    let expected = (
+        sdsl::bit_vector! {1, 0, 0, 0},
+        sdsl::bit_vector! {1, 1, 1, 0},
+        sdsl::bit_vector! {0, 1, 1, 0},
+        sdsl::bit_vector! {0, 0, 1, 1},
+    );
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_bitwise_assign_operators() -> Result<()> {
+    let mut bv = sdsl::bit_vector! {1, 1, 0, 0};
+    bv |= &sdsl::bit_vector! {0, 0, 1, 0};
+    bv &= &sdsl::bit_vector! {0, 1, 1, 1};
+    bv ^= sdsl::bit_vector! {0, 0, 0, 1};
+
+    let result = bv;
+    let expected = sdsl::bit_vector! {0, 1, 1, 1};
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_count_ones_and_zeros() -> Result<()> {
+    let bv = sdsl::bit_vector! {1, 1, 0, 1, 0};
+
+    let result = (bv.count_ones(), bv.count_zeros());
+    let expected = (3, 2);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_and_not() -> Result<()> {
+    let a = sdsl::bit_vector! {1, 1, 0, 1};
+    let b = sdsl::bit_vector! {0, 1, 1, 0};
+
+    let result = a.and_not(&b);
+    let expected = sdsl::bit_vector! {1, 0, 0, 1};
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_is_subset_of() -> Result<()> {
+    let a = sdsl::bit_vector! {0, 1, 0, 1};
+    let b = sdsl::bit_vector! {1, 1, 0, 1};
+
+    let result = (a.is_subset_of(&b), b.is_subset_of(&a));
+    let expected = (true, false);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_rank_support_on_combined_masks() -> Result<()> {
+    let a = sdsl::bit_vector! {1, 1, 0, 1, 0};
+    let b = sdsl::bit_vector! {0, 1, 1, 1, 0};
+    let mask = &a & &b;
+    let rs = sdsl::rank_supports::RankSupportV::<sdsl::bit_patterns::P1>::new(&mask)?;
+
+    let result = rs.rank(5);
+    let expected = 2;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_next_and_prev_one() -> Result<()> {
+    let mut bv = sdsl::bit_vectors::BitVector::new(200, 0)?;
+    bv.set(3, 1);
+    bv.set(130, 1);
+
+    let result = (
+        bv.next_one(0),
+        bv.next_one(4),
+        bv.next_one(131),
+        bv.prev_one(129),
+        bv.prev_one(500),
+        bv.prev_one(2),
+    );
+    let expected = (Some(3), Some(130), None, Some(3), Some(130), None);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_next_and_prev_zero() -> Result<()> {
+    let bv = sdsl::bit_vector! {1, 1, 0, 1, 1};
+
+    let result = (
+        bv.next_zero(0),
+        bv.next_zero(3),
+        bv.prev_zero(4),
+        bv.prev_zero(1),
+    );
+    let expected = (Some(2), None, Some(2), None);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_ones() -> Result<()> {
+    let mut bv = sdsl::bit_vectors::BitVector::new(150, 0)?;
+    for position in &[0, 63, 64, 149] {
+        bv.set(*position, 1);
+    }
+
+    let ones: sdsl::bit_vectors::Ones<sdsl::bit_vectors::BitVector> = bv.ones();
+    let result: Vec<_> = ones.collect();
+    let expected = vec![0, 63, 64, 149];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_available_instantiations() -> Result<()> {
+    let _bv = sdsl::bit_vectors::BitVector::new(1, 0)?;
+    let result = sdsl::available_instantiations()?
+        .iter()
+        .any(|instantiation| instantiation.c_type() == "sdsl::bit_vector");
+    let expected = true;
+    assert_eq!(result, expected);
+    Ok(())
+}
@@ -19,6 +19,29 @@ fn test_from_file() -> Result<()> {
     Ok(())
 }
 
+/// Listed in `build.rs` with `sdsl::spec!`.
+type Rrr63<'a> = sdsl::bit_vectors::RrrVector<'a, sdsl::int_vectors::IntVector<0>, 63, 32>;
+
+#[test]
+fn test_listed_type() -> Result<()> {
+    let bv = sdsl::bit_vector! {1, 1, 0, 1};
+    let rv = Rrr63::new(&bv)?;
+
+    let result: Vec<_> = rv.iter_bv().collect();
+    let expected = vec![1, 1, 0, 1];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_spec_type_name() -> Result<()> {
+    let spec = sdsl::spec!(sdsl::bit_vectors::RrrVector<sdsl::int_vectors::IntVector<0>, 63, 32>);
+    let result = spec.type_name();
+    let expected = "sdsl::bit_vectors::RrrVector<sdsl::int_vectors::IntVector<0>,63,32>";
+    assert_eq!(result, expected);
+    Ok(())
+}
+
 #[test]
 fn test_get_int() -> Result<()> {
     let bv = sdsl::bit_vector! {1, 1, 0, 1};
@@ -40,3 +63,44 @@ fn test_get_bv_element() -> Result<()> {
     assert_eq!(result, expected);
     Ok(())
 }
+
+#[test]
+fn test_try_get_bv_element() -> Result<()> {
+    let bv = sdsl::bit_vector! {1, 1, 0, 1};
+    let rv = sdsl::bit_vectors::RrrVector::<sdsl::int_vectors::IntVector<0>, 10, 2>::new(&bv)?;
+
+    let result = (rv.try_get_bv_element(2), rv.try_get_bv_element(4));
+    let expected = (
+        Ok(0),
+        Err(sdsl::Error::IndexOutOfBounds { index: 4, len: 4 }),
+    );
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_index() -> Result<()> {
+    let bv = sdsl::bit_vector! {1, 1, 0, 1};
+    let rv = sdsl::bit_vectors::RrrVector::<sdsl::int_vectors::IntVector<0>, 10, 2>::new(&bv)?;
+
+    let result = (rv[1], rv[2]);
+    let expected = (true, false);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_bit_scan() -> Result<()> {
+    let bv = sdsl::bit_vector! {0, 1, 0, 0, 1, 1};
+    let rv = sdsl::bit_vectors::RrrVector::<sdsl::int_vectors::IntVector<0>, 10, 2>::new(&bv)?;
+
+    let result = (
+        rv.next_one(2),
+        rv.prev_one(3),
+        rv.next_zero(4),
+        rv.ones().collect::<Vec<_>>(),
+    );
+    let expected = (Some(4), Some(1), None, vec![1, 4, 5]);
+    assert_eq!(result, expected);
+    Ok(())
+}
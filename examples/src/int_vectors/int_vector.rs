@@ -24,7 +24,7 @@ fn test_len_gives_number_of_elements() -> Result<()> {
 #[test]
 fn test_correct_len_after_resize() -> Result<()> {
     let mut iv = sdsl::int_vectors::IntVector::<0>::new(5, 42, Some(64))?;
-    iv.resize(6);
+    iv.resize(6)?;
 
     let result = iv.len();
     let expected = 6;
@@ -35,7 +35,7 @@ fn test_correct_len_after_resize() -> Result<()> {
 #[test]
 fn test_resize_truncates_vector() -> Result<()> {
     let mut iv = sdsl::int_vector! {1, 42, 3};
-    iv.resize(2);
+    iv.resize(2)?;
 
     let result: Vec<_> = iv.iter().collect();
     let expected = vec![1, 42];
@@ -48,7 +48,7 @@ fn test_correct_bit_size_after_resize() -> Result<()> {
     let mut iv = sdsl::int_vectors::IntVector::<0>::new(5, 42, Some(64))?;
     let before_bit_size = iv.bit_size();
 
-    iv.bit_resize(30);
+    iv.bit_resize(30)?;
     let after_bit_size = iv.bit_size();
 
     let result = (before_bit_size, after_bit_size);
@@ -60,7 +60,7 @@ fn test_correct_bit_size_after_resize() -> Result<()> {
 #[test]
 fn test_bit_resize_total_vector_thereby_truncating() -> Result<()> {
     let mut iv = sdsl::int_vector! {1, 42, 3};
-    iv.bit_resize(2 * iv.width() as usize);
+    iv.bit_resize(2 * iv.width() as usize)?;
 
     let result: Vec<_> = iv.iter().collect();
     let expected = vec![1, 42];
@@ -144,3 +144,268 @@ fn test_max_size() -> Result<()> {
     assert!(result > 0);
     Ok(())
 }
+
+#[test]
+fn test_get_range() -> Result<()> {
+    let iv = sdsl::int_vector! {1, 42, 3, 7};
+    let mut values = [0; 2];
+    iv.get_range(1, &mut values);
+
+    let result = values;
+    let expected = [42, 3];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_set_range() -> Result<()> {
+    let mut iv = sdsl::int_vector! {1, 42, 3, 7};
+    iv.set_range(2, &[5, 6]);
+
+    let result: Vec<_> = iv.iter().collect();
+    let expected = vec![1, 42, 5, 6];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_iter_over_multiple_chunks() -> Result<()> {
+    let mut iv = sdsl::int_vectors::IntVector::<0>::new(3000, 0, Some(64))?;
+    sdsl::util::set_to_id(&mut iv);
+
+    let result: Vec<_> = iv.iter().collect();
+    let expected: Vec<_> = (0..3000).collect();
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_collect_from_iterator() -> Result<()> {
+    let iv: sdsl::int_vectors::IntVector<0> = (1..4).collect();
+
+    let result = (iv.iter().collect::<Vec<_>>(), iv.width());
+    let expected = (vec![1, 2, 3], 64);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_from_slice() -> Result<()> {
+    let iv = sdsl::int_vectors::IntVector::<28>::from(&[1, 42, 3][..]);
+
+    let result = (iv.iter().collect::<Vec<_>>(), iv.width());
+    let expected = (vec![1, 42, 3], 28);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_from_slice_with_width() -> Result<()> {
+    let iv = sdsl::int_vectors::IntVector::<0>::from_slice_with_width(&[1, 12, 3])?;
+
+    let result = (iv.iter().collect::<Vec<_>>(), iv.width());
+    let expected = (vec![1, 12, 3], 4);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_extend() -> Result<()> {
+    let mut iv = sdsl::int_vector! {1, 42};
+    iv.extend(vec![3, 4]);
+
+    let result: Vec<_> = iv.iter().collect();
+    let expected = vec![1, 42, 3, 4];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "Value 256 does not fit within integer width 8.")]
+fn test_from_slice_panics_on_width_overflow() {
+    let values: &[u64] = &[1, 256];
+    let _ = sdsl::int_vectors::IntVector::<8>::from(values);
+}
+
+#[test]
+#[should_panic(expected = "Value 300 does not fit within integer width 8.")]
+fn test_extend_panics_on_width_overflow() {
+    let mut iv: sdsl::int_vectors::IntVector<8> = vec![1, 2].into_iter().collect();
+    iv.extend(vec![3, 300]);
+}
+
+#[test]
+fn test_push() -> Result<()> {
+    let mut iv = sdsl::int_vectors::IntVector::<0>::new(0, 0, Some(8))?;
+    for value in 0..100 {
+        iv.push(value)?;
+    }
+
+    let result: Vec<_> = iv.iter().collect();
+    let expected: Vec<_> = (0..100).collect();
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_push_error_on_width_overflow() -> Result<()> {
+    let mut iv = sdsl::int_vectors::IntVector::<0>::new(0, 0, Some(4))?;
+    assert!(iv.push(16).is_err());
+    assert!(iv.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_new_invalid_width() -> Result<()> {
+    let result = (
+        matches!(
+            sdsl::int_vectors::IntVector::<0>::new(5, 0, None),
+            Err(sdsl::Error::InvalidArgument(_))
+        ),
+        matches!(
+            sdsl::int_vectors::IntVector::<0>::new(5, 0, Some(65)),
+            Err(sdsl::Error::InvalidArgument(_))
+        ),
+    );
+    let expected = (true, true);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_new_reports_cpp_exception() -> Result<()> {
+    // Allocating 2^60 bytes makes SDSL throw std::bad_alloc.
+    let failed = sdsl::int_vectors::IntVector::<0>::new(1 << 60, 0, Some(8));
+    let iv = sdsl::int_vectors::IntVector::<0>::new(2, 7, Some(8))?;
+
+    let result = (
+        matches!(failed, Err(sdsl::Error::CppException(_))),
+        iv.iter().collect::<Vec<_>>(),
+    );
+    let expected = (true, vec![7, 7]);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_resize_reports_cpp_exception() -> Result<()> {
+    let mut iv = sdsl::int_vectors::IntVector::<0>::new(2, 7, Some(8))?;
+    let failed = iv.resize(1 << 60);
+
+    let result = matches!(failed, Err(sdsl::Error::CppException(_)));
+    let expected = true;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_pop() -> Result<()> {
+    let mut iv = sdsl::int_vector! {1, 42};
+
+    let result = (iv.pop(), iv.pop(), iv.pop());
+    let expected = (Some(42), Some(1), None);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_truncate() -> Result<()> {
+    let mut iv = sdsl::int_vector! {1, 42, 3};
+    iv.truncate(1);
+    iv.truncate(5);
+
+    let result: Vec<_> = iv.iter().collect();
+    let expected = vec![1];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_reserve_and_shrink_to_fit() -> Result<()> {
+    let mut iv = sdsl::int_vector! {1, 42, 3};
+    iv.reserve(100);
+    let reserved_capacity = iv.capacity();
+    iv.shrink_to_fit();
+
+    let result = (reserved_capacity, iv.capacity(), iv.len());
+    let expected = (192, 192, 3);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_swap() -> Result<()> {
+    let mut iv = sdsl::int_vector! {1, 42, 3};
+    iv.swap(0, 2);
+
+    let result: Vec<_> = iv.iter().collect();
+    let expected = vec![3, 42, 1];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_extend_from_slice() -> Result<()> {
+    let mut iv = sdsl::int_vectors::IntVector::<0>::new(1, 7, Some(4))?;
+    iv.extend_from_slice(&[1, 2])?;
+    assert!(iv.extend_from_slice(&[3, 16]).is_err());
+
+    let result: Vec<_> = iv.iter().collect();
+    let expected = vec![7, 1, 2];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_try_get() -> Result<()> {
+    let iv = sdsl::int_vector! {1, 42};
+
+    let result = (iv.try_get(1), iv.try_get(2));
+    let expected = (
+        Ok(42),
+        Err(sdsl::Error::IndexOutOfBounds { index: 2, len: 2 }),
+    );
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "Index 2 out of bounds for length 2.")]
+fn test_get_panics_out_of_bounds() {
+    let iv: sdsl::int_vectors::IntVector<0> = vec![1, 42].into_iter().collect();
+    iv.get(2);
+}
+
+#[test]
+fn test_at_mut() -> Result<()> {
+    let mut iv = sdsl::int_vector! {1, 12, 3};
+    let mut element: sdsl::int_vectors::IntVectorElementMut<0> = iv.at_mut(1);
+    element.set(element.get() + 30);
+
+    let result: Vec<_> = iv.iter().collect();
+    let expected = vec![1, 42, 3];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_update() -> Result<()> {
+    let mut iv = sdsl::int_vector! {1, 12, 3};
+    iv.update(2, |value| value + 1);
+
+    let result: Vec<_> = iv.iter().collect();
+    let expected = vec![1, 12, 4];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_slice() -> Result<()> {
+    let iv = sdsl::int_vector! {1, 12, 3, 42};
+    let slice: sdsl::VectorSlice<usize, sdsl::int_vectors::IntVector<0>> = iv.slice(1..3);
+
+    let result = (slice.len(), slice.get(1), slice.iter().collect::<Vec<_>>());
+    let expected = (2, 3, vec![12, 3]);
+    assert_eq!(result, expected);
+    Ok(())
+}
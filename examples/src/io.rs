@@ -35,3 +35,352 @@ fn test_store_and_load_from_file() -> Result<()> {
     assert_eq!(result, expected);
     Ok(())
 }
+
+#[test]
+fn test_from_file_missing_file() -> Result<()> {
+    let tmp_dir = tempdir::TempDir::new("sdsl-rs-tests")?;
+    let path = tmp_dir.path().join("missing.bin");
+
+    let result = (
+        sdsl::bit_vectors::BitVector::from_file(&path).is_err(),
+        sdsl::wavelet_trees::WtHuff::<sdsl::bit_vectors::BitVector>::from_file(&path).is_err(),
+    );
+    let expected = (true, true);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_from_file_missing_file_error_kind() -> Result<()> {
+    let tmp_dir = tempdir::TempDir::new("sdsl-rs-tests")?;
+    let path = tmp_dir.path().join("missing.bin");
+
+    let result = match sdsl::bit_vectors::BitVector::from_file(&path) {
+        Err(sdsl::Error::Io { kind, .. }) => Some(kind),
+        _ => None,
+    };
+    let expected = Some(std::io::ErrorKind::NotFound);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_from_file_missing_file_error_source() -> Result<()> {
+    let tmp_dir = tempdir::TempDir::new("sdsl-rs-tests")?;
+    let path = tmp_dir.path().join("missing.bin");
+
+    let result = match sdsl::bit_vectors::BitVector::from_file(&path) {
+        Err(error) => std::error::Error::source(&error)
+            .and_then(|source| source.downcast_ref::<std::io::Error>())
+            .map(|source| source.kind()),
+        Ok(_) => None,
+    };
+    let expected = Some(std::io::ErrorKind::NotFound);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_from_file_truncated_file() -> Result<()> {
+    let tmp_dir = tempdir::TempDir::new("sdsl-rs-tests")?;
+    let path = tmp_dir.path().join("int_vector.bin");
+
+    let iv = sdsl::int_vector! {1, 2, 3, 4};
+    sdsl::io::store_to_file(&iv, &path)?;
+    let bytes = std::fs::read(&path)?;
+    std::fs::write(&path, &bytes[..bytes.len() - 4])?;
+
+    let result = sdsl::int_vectors::IntVector::<0>::from_file(&path).is_err();
+    let expected = true;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_from_file_wrong_type() -> Result<()> {
+    let tmp_dir = tempdir::TempDir::new("sdsl-rs-tests")?;
+    let path = tmp_dir.path().join("wavelet_tree.bin");
+
+    let wt = sdsl::wavelet_trees::WtHuff::<sdsl::bit_vectors::BitVector>::from_str("banana")?;
+    sdsl::io::store_to_file(&wt, &path)?;
+
+    let result = sdsl::bit_vectors::BitVector::from_file(&path).is_err();
+    let expected = true;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_store_to_file_missing_directory() -> Result<()> {
+    let tmp_dir = tempdir::TempDir::new("sdsl-rs-tests")?;
+    let path = tmp_dir.path().join("missing").join("int_vector.bin");
+
+    let iv = sdsl::int_vector! {1, 2, 3, 4};
+    let result = sdsl::io::store_to_file(&iv, &path).is_err();
+    let expected = true;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_size_in_bytes() -> Result<()> {
+    use sdsl::io::SpaceUsage;
+    let iv = sdsl::int_vectors::IntVector::<0>::new(64, 1, Some(64))?;
+
+    // Length (8 bytes), width (1 byte) and 64 words of data.
+    let result = (iv.size_in_bytes(), iv.size_in_mega_bytes());
+    let expected = (521, 521.0 / (1024.0 * 1024.0));
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_structure_tree() -> Result<()> {
+    use sdsl::io::SpaceUsage;
+    let bv = sdsl::bit_vector! {1, 1, 0, 1};
+    let wt = sdsl::wavelet_trees::WtInt::<sdsl::bit_vectors::BitVector>::from_bit_vector(&bv)?;
+
+    let tree = wt.structure_tree()?;
+    let children_size: u64 = tree.children.iter().map(|child| child.size_in_bytes).sum();
+
+    let result = (
+        tree.size_in_bytes,
+        tree.class_name.contains("wt_int"),
+        tree.children.is_empty(),
+        children_size,
+    );
+    let expected = (wt.size_in_bytes(), true, false, wt.size_in_bytes());
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_write_structure_json() -> Result<()> {
+    let iv = sdsl::int_vectors::IntVector::<0>::new(64, 1, Some(64))?;
+    let mut report = Vec::new();
+    sdsl::io::write_structure(&iv, sdsl::io::Format::Json, &mut report)?;
+
+    let tree = sdsl::io::StructureTree::from_json(std::str::from_utf8(&report)?)?;
+    let result = tree.size_in_bytes;
+    let expected = 521;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_write_structure_html() -> Result<()> {
+    let iv = sdsl::int_vectors::IntVector::<0>::new(64, 1, Some(64))?;
+    let mut report = Vec::new();
+    sdsl::io::write_structure(&iv, sdsl::io::Format::Html, &mut report)?;
+
+    let result = String::from_utf8(report)?.contains("<html>");
+    let expected = true;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_structure_tree_from_json() -> Result<()> {
+    let json = r#"{"class_name":"int_vector","name":"","size":"521","children":[
+        {"class_name":"uint64_t","name":"size","size":"8"},
+        {"class_name":"uint8_t","name":"width","size":"1"},
+        {"class_name":"uint64_t*","name":"data","size":"512"}
+    ]}"#;
+
+    let tree = sdsl::io::StructureTree::from_json(json)?;
+    let result: Vec<_> = tree
+        .children
+        .iter()
+        .map(|child| (child.name.as_str(), child.size_in_bytes))
+        .collect();
+    let expected = vec![("size", 8), ("width", 1), ("data", 512)];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_serialize_and_load() -> Result<()> {
+    use sdsl::io::SpaceUsage;
+    let mut iv = sdsl::int_vectors::IntVector::<0>::new(5, 12, Some(28))?;
+    iv.set(2, 42);
+
+    let mut buffer = Vec::new();
+    let written = iv.serialize(&mut buffer)?;
+    let loaded_iv = sdsl::int_vectors::IntVector::<0>::load(&mut std::io::Cursor::new(&buffer))?;
+
+    let result = (
+        written,
+        buffer.len() as u64,
+        loaded_iv.iter().collect::<Vec<_>>(),
+    );
+    let expected = (buffer.len(), iv.size_in_bytes(), vec![12, 12, 42, 12, 12]);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_serialize_and_load_wavelet_tree() -> Result<()> {
+    let wt = sdsl::wavelet_trees::WtHuff::<sdsl::bit_vectors::BitVector>::from_str("banana")?;
+
+    let mut buffer = Vec::new();
+    sdsl::io::serialize(&wt, &mut buffer)?;
+    let loaded_wt =
+        sdsl::wavelet_trees::WtHuff::<sdsl::bit_vectors::BitVector>::load(&mut buffer.as_slice())?;
+
+    let result: Vec<_> = loaded_wt.iter().collect();
+    let expected: Vec<_> = "banana".bytes().collect();
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_serialize_and_load_rank_support() -> Result<()> {
+    let bv = sdsl::bit_vector! {1, 1, 0, 1, 0, 0, 1};
+    let rs = sdsl::rank_supports::RankSupportV::<sdsl::bit_patterns::P1>::new(&bv)?;
+
+    let mut buffer = Vec::new();
+    rs.serialize(&mut buffer)?;
+    let loaded_rs = sdsl::rank_supports::RankSupportV::<sdsl::bit_patterns::P1>::load(
+        &mut buffer.as_slice(),
+        &bv,
+    )?;
+
+    let result = loaded_rs.rank(7);
+    let expected = 4;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_serialize_and_load_select_support() -> Result<()> {
+    let bv = sdsl::bit_vector! {1, 1, 0, 1, 0, 0, 1};
+    let ss = sdsl::select_supports::SelectSupportMcl::<sdsl::bit_patterns::P1>::new(&bv)?;
+
+    let mut buffer = Vec::new();
+    ss.serialize(&mut buffer)?;
+    let loaded_ss = sdsl::select_supports::SelectSupportMcl::<sdsl::bit_patterns::P1>::load(
+        &mut buffer.as_slice(),
+        &bv,
+    )?;
+
+    let result = (loaded_ss.select(4), loaded_ss.try_select(5).is_err());
+    let expected = (6, true);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+struct FailingWriter;
+
+impl std::io::Write for FailingWriter {
+    fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::WriteZero,
+            "disk full",
+        ))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_serialize_reports_write_errors() -> Result<()> {
+    let bv = sdsl::bit_vector! {1, 0, 1};
+    let result = bv.serialize(&mut FailingWriter).is_err();
+    let expected = true;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_to_bytes_matches_file_format() -> Result<()> {
+    let tmp_dir = tempdir::TempDir::new("sdsl-rs-tests")?;
+    let path = tmp_dir.path().join("bit_vector.bin");
+
+    let bv = sdsl::bit_vector! {1, 1, 0, 1};
+    sdsl::io::store_to_file(&bv, &path)?;
+
+    let result = bv.to_bytes()?;
+    let expected = std::fs::read(&path)?;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_to_bytes_and_from_bytes() -> Result<()> {
+    let bv = sdsl::bit_vector! {1, 1, 0, 1};
+    let rv = sdsl::bit_vectors::RrrVector::<sdsl::int_vectors::IntVector<0>, 10, 2>::new(&bv)?;
+
+    let bytes = rv.to_bytes()?;
+    let loaded_rv =
+        sdsl::bit_vectors::RrrVector::<sdsl::int_vectors::IntVector<0>, 10, 2>::from_bytes(&bytes)?;
+
+    let result: Vec<_> = loaded_rv.iter_bv().collect();
+    let expected = vec![1, 1, 0, 1];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_to_bytes_and_from_bytes_select_support() -> Result<()> {
+    let bv = sdsl::bit_vector! {0, 1, 0, 1, 0, 0, 0};
+    let ss = sdsl::select_supports::SelectSupportMcl::<sdsl::bit_patterns::P1>::new(&bv)?;
+
+    let bytes = ss.to_bytes()?;
+    let loaded_ss =
+        sdsl::select_supports::SelectSupportMcl::<sdsl::bit_patterns::P1>::from_bytes(&bytes, &bv)?;
+
+    let result = loaded_ss.select(2);
+    let expected = 3;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Index {
+    name: String,
+    bits: sdsl::bit_vectors::BitVector,
+    values: sdsl::int_vectors::IntVector<0>,
+}
+
+#[test]
+fn test_serde_round_trip() -> Result<()> {
+    let index = Index {
+        name: "index".to_string(),
+        bits: sdsl::bit_vector! {1, 0, 0, 1},
+        values: sdsl::int_vector! {3, 7, 11},
+    };
+
+    let bytes = bincode::serialize(&index)?;
+    let loaded: Index = bincode::deserialize(&bytes)?;
+
+    let result = (
+        loaded.name,
+        loaded.bits.iter().collect::<Vec<_>>(),
+        loaded.values.iter().collect::<Vec<_>>(),
+    );
+    let expected = ("index".to_string(), vec![1, 0, 0, 1], vec![3, 7, 11]);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_serde_round_trip_rank_support() -> Result<()> {
+    use bincode::Options;
+
+    let bv = sdsl::bit_vector! {1, 1, 0, 1, 0, 0, 1};
+    let rs = sdsl::rank_supports::RankSupportV::<sdsl::bit_patterns::P1>::new(&bv)?;
+
+    let bytes = bincode::serialize(&rs)?;
+    let seed = sdsl::rank_supports::RankSupportV::<sdsl::bit_patterns::P1>::deserialize_seed(&bv);
+    let loaded_rs = bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .allow_trailing_bytes()
+        .deserialize_seed(seed, &bytes)?;
+
+    let result = loaded_rs.rank(4);
+    let expected = 3;
+    assert_eq!(result, expected);
+    Ok(())
+}
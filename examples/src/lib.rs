@@ -4,6 +4,9 @@ mod util;
 #[cfg(test)]
 mod io;
 
+#[cfg(test)]
+mod library;
+
 #[cfg(test)]
 mod int_vectors;
 
@@ -0,0 +1,26 @@
+use anyhow::Result;
+
+#[test]
+fn test_init_with_library_missing_file() -> Result<()> {
+    let path = std::path::PathBuf::from("missing/libsdsl_c.so");
+    let result = match sdsl::init_with_library(&path) {
+        Err(sdsl::Error::Io { kind, .. }) => Some(kind),
+        _ => None,
+    };
+    let expected = Some(std::io::ErrorKind::NotFound);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_init_with_library_after_load() -> Result<()> {
+    let _bv = sdsl::bit_vectors::BitVector::new(1, 0)?;
+    let path = std::env::current_exe()?;
+    let result = matches!(
+        sdsl::init_with_library(&path),
+        Err(sdsl::Error::InvalidArgument(_))
+    );
+    let expected = true;
+    assert_eq!(result, expected);
+    Ok(())
+}
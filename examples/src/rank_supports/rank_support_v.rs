@@ -43,3 +43,28 @@ fn test_rank_p01() -> Result<()> {
     assert_eq!(result, expected);
     Ok(())
 }
+
+#[test]
+fn test_rank_many() -> Result<()> {
+    let bv = sdsl::bit_vector! {1, 1, 0, 1, 0, 0, 0};
+    let rs = sdsl::rank_supports::RankSupportV::<sdsl::bit_patterns::P1>::new(&bv)?;
+
+    let result = rs.rank_many(&[0, 2, 5]);
+    let expected = vec![0, 2, 3];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_try_rank() -> Result<()> {
+    let bv = sdsl::bit_vector! {1, 1, 0, 1};
+    let rs = sdsl::rank_supports::RankSupportV::<sdsl::bit_patterns::P1>::new(&bv)?;
+
+    let result = (rs.try_rank(4), rs.try_rank(5));
+    let expected = (
+        Ok(3),
+        Err(sdsl::Error::IndexOutOfBounds { index: 5, len: 5 }),
+    );
+    assert_eq!(result, expected);
+    Ok(())
+}
@@ -43,3 +43,52 @@ fn test_select_p01() -> Result<()> {
     assert_eq!(result, expected);
     Ok(())
 }
+
+#[test]
+fn test_select_many() -> Result<()> {
+    let bv = sdsl::bit_vector! {1, 1, 0, 1, 0, 1, 0};
+    let ss = sdsl::select_supports::SelectSupportMcl::<sdsl::bit_patterns::P1>::new(&bv)?;
+
+    let result = ss.select_many(&[1, 3, 4]);
+    let expected = vec![0, 3, 5];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_try_select() -> Result<()> {
+    let bv = sdsl::bit_vector! {0, 1, 0, 0, 1, 0, 0, 0};
+    let ss = sdsl::select_supports::SelectSupportMcl::<sdsl::bit_patterns::P10>::new(&bv)?;
+
+    let result = (ss.try_select(0), ss.try_select(2), ss.try_select(3));
+    let expected = (
+        Err(sdsl::Error::OccurrenceOutOfRange {
+            occurrence: 0,
+            count: Some(2),
+        }),
+        Ok(5),
+        Err(sdsl::Error::OccurrenceOutOfRange {
+            occurrence: 3,
+            count: Some(2),
+        }),
+    );
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_try_select_counts_instances_across_words() -> Result<()> {
+    let bv: sdsl::bit_vectors::BitVector = (0..130).map(|i| i % 3 == 0).collect();
+    let ss = sdsl::select_supports::SelectSupportMcl::<sdsl::bit_patterns::P01>::new(&bv)?;
+
+    let result = (ss.try_select(43), ss.try_select(44));
+    let expected = (
+        Ok(129),
+        Err(sdsl::Error::OccurrenceOutOfRange {
+            occurrence: 44,
+            count: Some(43),
+        }),
+    );
+    assert_eq!(result, expected);
+    Ok(())
+}
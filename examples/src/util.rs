@@ -24,7 +24,21 @@ fn test_set_to_id() -> Result<()> {
 fn test_set_random_bits() -> Result<()> {
     let mut iv = sdsl::int_vectors::IntVector::<28>::new(5, 12, None)?;
     sdsl::util::set_random_bits(&mut iv);
-    // TODO: Freeze seed in test.
+    let result = iv.len();
+    let expected = 5;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_set_random_bits_with_seed() -> Result<()> {
+    let mut iv_a = sdsl::int_vectors::IntVector::<28>::new(5, 12, None)?;
+    let mut iv_b = sdsl::int_vectors::IntVector::<28>::new(5, 12, None)?;
+    sdsl::util::set_random_bits_with_seed(&mut iv_a, 42);
+    sdsl::util::set_random_bits_with_seed(&mut iv_b, 42);
+    let result: Vec<_> = iv_a.iter().collect();
+    let expected: Vec<_> = iv_b.iter().collect();
+    assert_eq!(result, expected);
     Ok(())
 }
 
@@ -51,9 +65,64 @@ fn test_bit_compress() -> Result<()> {
 #[test]
 fn test_expand_width() -> Result<()> {
     let mut iv = sdsl::int_vectors::IntVector::<0>::new(5, 12, Some(20))?;
-    sdsl::util::expand_width(&mut iv, 21);
+    sdsl::util::expand_width(&mut iv, 21)?;
     let result = iv.width();
     let expected = 21;
     assert_eq!(result, expected);
     Ok(())
 }
+
+#[test]
+fn test_cnt_bits() -> Result<()> {
+    let bv = sdsl::bit_vector! {1, 1, 0, 1, 0, 0, 1};
+    let result = (
+        sdsl::util::cnt_one_bits(&bv),
+        sdsl::util::cnt_onezero_bits(&bv),
+        sdsl::util::cnt_zeroone_bits(&bv),
+    );
+    let expected = (4, 2, 2);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_clear() -> Result<()> {
+    let mut iv = sdsl::int_vectors::IntVector::<28>::new(5, 12, None)?;
+    sdsl::util::clear(&mut iv);
+    let result = iv.len();
+    let expected = 0;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_swap() -> Result<()> {
+    let mut iv_a = sdsl::int_vectors::IntVector::<28>::new(2, 1, None)?;
+    let mut iv_b = sdsl::int_vectors::IntVector::<28>::new(3, 2, None)?;
+    sdsl::util::swap(&mut iv_a, &mut iv_b);
+    let result: Vec<_> = iv_a.iter().collect();
+    let expected = vec![2, 2, 2];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_to_string() -> Result<()> {
+    let iv = sdsl::int_vectors::IntVector::<28>::new(3, 7, None)?;
+    let result: Vec<_> = sdsl::util::to_string(&iv)
+        .split_whitespace()
+        .map(|value| value.to_string())
+        .collect();
+    let expected = vec!["7", "7", "7"];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_class_name() -> Result<()> {
+    let bv = sdsl::bit_vector! {1, 0};
+    let result = sdsl::util::class_name(&bv).contains("int_vector");
+    let expected = true;
+    assert_eq!(result, expected);
+    Ok(())
+}
@@ -1,5 +1,17 @@
 use anyhow::Result;
 
+#[test]
+fn test_get_many() -> Result<()> {
+    #[rustfmt::skip]
+    //                                   | 113                |  | 115                      |
+    let bv = sdsl::bit_vector! {1, 0, 0, 0, 1, 1, 1, 0, 1, 1, 0, 0, 1, 1, 1, 0};
+    let wt = sdsl::wavelet_trees::WtHuff::<sdsl::bit_vectors::BitVector>::from_bit_vector(&bv)?;
+    let result = wt.get_many(&[1, 0]);
+    let expected = vec![115, 113];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
 #[test]
 fn test_rank() -> Result<()> {
     #[rustfmt::skip]
@@ -147,3 +159,21 @@ fn test_alphabet_size() -> Result<()> {
     assert_eq!(result, expected);
     Ok(())
 }
+
+#[test]
+fn test_try_select() -> Result<()> {
+    #[rustfmt::skip]
+    //                                   | 113                |  | 113                |  | 115                |
+    let bv = sdsl::bit_vector! {1, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 1, 1, 1, 0, 1, 1, 0, 0, 1, 1, 1, 0};
+    let wt = sdsl::wavelet_trees::WtHuff::<sdsl::bit_vectors::BitVector>::from_bit_vector(&bv)?;
+    let result = (wt.try_select(2, 113), wt.try_select(1, 114));
+    let expected = (
+        Ok(1),
+        Err(sdsl::Error::OccurrenceOutOfRange {
+            occurrence: 1,
+            count: Some(0),
+        }),
+    );
+    assert_eq!(result, expected);
+    Ok(())
+}
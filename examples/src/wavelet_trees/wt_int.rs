@@ -10,6 +10,16 @@ fn test_get() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_get_many() -> Result<()> {
+    let iv = sdsl::int_vector! {1, 1, 7, 1, 1, 2, 3, 4};
+    let wt = sdsl::wavelet_trees::WtInt::<sdsl::bit_vectors::BitVector>::from_int_vector(&iv)?;
+    let result = wt.get_many(&[2, 5, 7]);
+    let expected = vec![7, 2, 4];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
 #[test]
 fn test_rank() -> Result<()> {
     let iv = sdsl::int_vector! {1, 1, 2, 1, 1, 2, 3, 4};
@@ -137,3 +147,19 @@ fn test_alphabet_size() -> Result<()> {
     assert_eq!(result, expected);
     Ok(())
 }
+
+#[test]
+fn test_try_select() -> Result<()> {
+    let iv = sdsl::int_vector! {1, 1, 2, 1, 1, 2, 3, 4};
+    let wt = sdsl::wavelet_trees::WtInt::<sdsl::bit_vectors::BitVector>::from_int_vector(&iv)?;
+    let result = (wt.try_select(2, 2), wt.try_select(3, 2));
+    let expected = (
+        Ok(5),
+        Err(sdsl::Error::OccurrenceOutOfRange {
+            occurrence: 3,
+            count: Some(2),
+        }),
+    );
+    assert_eq!(result, expected);
+    Ok(())
+}
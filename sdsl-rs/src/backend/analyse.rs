@@ -1,26 +1,217 @@
 use anyhow::{format_err, Result};
 
-use crate::backend::{common, sdsl_c::specification};
+use crate::backend::{
+    common,
+    sdsl_c::{manifest, specification},
+    spec::Spec,
+};
 use crate::meta;
 
 pub struct CodeMeta {
     pub mir: String,
 }
 
+/// Generate code metadata for the crate and for the local crates it depends on which use SDSL.
+///
+/// Local crates are the workspace members and path dependencies. Their MIR is merged, so that a single shared library
+/// covers the structures used by the final artifact and by the libraries it depends on.
 pub fn setup(
-    crate_directory: &std::path::PathBuf,
-    out_directory: &std::path::PathBuf,
+    crate_directory: &std::path::Path,
+    out_directory: &std::path::Path,
 ) -> Result<Option<CodeMeta>> {
     log::debug!("Generating code metadata.");
-    Ok(match get_mir_file_path(&crate_directory, &out_directory)? {
-        Some(path) => {
+    let mut mirs = Vec::<_>::new();
+    for package in get_sdsl_packages(crate_directory)? {
+        log::debug!("Analysing crate: {}", package.name);
+        let package_directory = package
+            .manifest_path
+            .parent()
+            .ok_or(format_err!(
+                "Failed to find directory of manifest: {}",
+                package.manifest_path.display()
+            ))?
+            .to_path_buf();
+        println!(
+            "cargo:rerun-if-changed={}",
+            package_directory.join("src").display()
+        );
+        let mir_tmp_directory = out_directory.join("mir_build").join(&package.name);
+        if let Some(path) = get_mir_file_path(
+            &package_directory,
+            &mir_tmp_directory,
+            &package.crate_names(),
+        )? {
             let mir = std::fs::read_to_string(&path)?;
-            let mir = clean_mir(&mir);
-            std::fs::write(out_directory.join("mir_clean"), &mir)?;
-            Some(CodeMeta { mir })
+            mirs.push(clean_mir(&mir));
+        }
+    }
+    if mirs.is_empty() {
+        return Ok(None);
+    }
+
+    let mir = mirs.join("\n");
+    std::fs::write(out_directory.join("mir_clean"), &mir)?;
+    Ok(Some(CodeMeta { mir }))
+}
+
+#[derive(serde::Deserialize)]
+#[serde(crate = "serde")]
+struct Metadata {
+    packages: Vec<Package>,
+    resolve: Option<Resolve>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(crate = "serde")]
+struct Package {
+    id: String,
+    name: String,
+    source: Option<String>,
+    manifest_path: std::path::PathBuf,
+    dependencies: Vec<Dependency>,
+    targets: Vec<Target>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(crate = "serde")]
+struct Dependency {
+    name: String,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(crate = "serde")]
+struct Target {
+    name: String,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(crate = "serde")]
+struct Resolve {
+    nodes: Vec<Node>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(crate = "serde")]
+struct Node {
+    id: String,
+    deps: Vec<NodeDependency>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(crate = "serde")]
+struct NodeDependency {
+    pkg: String,
+    // Absent in the output of cargo versions before 1.41.
+    #[serde(default)]
+    dep_kinds: Vec<DependencyKind>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(crate = "serde")]
+struct DependencyKind {
+    kind: Option<String>,
+}
+
+impl Package {
+    /// Returns the crate names of the package's targets, which prefix the names of their MIR files.
+    fn crate_names(&self) -> Vec<String> {
+        let mut names = vec![self.name.replace('-', "_")];
+        for target in &self.targets {
+            let name = target.name.replace('-', "_");
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    }
+}
+
+/// Returns the crate in `crate_directory` and the local crates it depends on which depend on SDSL.
+fn get_sdsl_packages(crate_directory: &std::path::Path) -> Result<Vec<Package>> {
+    log::debug!("Executing command: cargo metadata --format-version 1");
+    let output = std::process::Command::new("cargo")
+        .args(vec!["metadata", "--format-version", "1"])
+        .current_dir(crate_directory)
+        .stderr(std::process::Stdio::piped())
+        .output()?;
+    if !output.status.success() {
+        return Err(format_err!(
+            "Failed to read cargo metadata.\nStderr:\n{stderr}",
+            stderr = String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    let metadata: Metadata = serde_json::from_slice(&output.stdout)?;
+
+    let manifest_path = crate_directory.join("Cargo.toml").canonicalize()?;
+    sdsl_packages(metadata, &manifest_path)
+}
+
+/// Walks the resolved dependency graph from the package of `manifest_path`.
+///
+/// Returns that package and the reachable local crates which depend on SDSL. Build dependencies, and development
+/// dependencies of other packages, are not followed since they are not part of the final artifact. Crates from registries and git repositories are skipped,
+/// since they can not be rebuilt to emit MIR.
+fn sdsl_packages(metadata: Metadata, manifest_path: &std::path::Path) -> Result<Vec<Package>> {
+    let root = metadata
+        .packages
+        .iter()
+        .find(|package| {
+            package.manifest_path == manifest_path
+                || package.manifest_path.canonicalize().ok().as_deref() == Some(manifest_path)
+        })
+        .ok_or(format_err!(
+            "Failed to find package of manifest: {}",
+            manifest_path.display()
+        ))?
+        .id
+        .clone();
+    let nodes: std::collections::BTreeMap<_, _> = metadata
+        .resolve
+        .map(|resolve| resolve.nodes)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|node| (node.id.clone(), node))
+        .collect();
+
+    let mut reachable = std::collections::BTreeSet::new();
+    let mut stack = vec![root.clone()];
+    while let Some(id) = stack.pop() {
+        if !reachable.insert(id.clone()) {
+            continue;
+        }
+        if let Some(node) = nodes.get(&id) {
+            for dependency in &node.deps {
+                let followed = dependency.dep_kinds.is_empty()
+                    || dependency
+                        .dep_kinds
+                        .iter()
+                        .any(|kind| match kind.kind.as_deref() {
+                            None => true,
+                            Some("dev") => id == root,
+                            _ => false,
+                        });
+                if followed {
+                    stack.push(dependency.pkg.clone());
+                }
+            }
         }
-        None => None,
-    })
+    }
+
+    let mut packages: Vec<_> = metadata
+        .packages
+        .into_iter()
+        .filter(|package| reachable.contains(&package.id))
+        .filter(|package| {
+            let uses_sdsl = package.source.is_none()
+                && package
+                    .dependencies
+                    .iter()
+                    .any(|dependency| dependency.name == "sdsl");
+            package.id == root || uses_sdsl
+        })
+        .collect();
+    packages.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(packages)
 }
 
 fn clean_mir(mir: &str) -> String {
@@ -37,12 +228,12 @@ fn clean_mir(mir: &str) -> String {
 }
 
 fn get_mir_file_path(
-    crate_directory: &std::path::PathBuf,
-    out_directory: &std::path::PathBuf,
+    package_directory: &std::path::Path,
+    mir_tmp_directory: &std::path::Path,
+    crate_names: &[String],
 ) -> Result<Option<std::path::PathBuf>> {
     log::debug!("Constructing MIR file.");
-    let mir_tmp_directory = out_directory.join("mir_build");
-    std::fs::create_dir_all(&mir_tmp_directory)?;
+    std::fs::create_dir_all(mir_tmp_directory)?;
 
     log::debug!(
         "Executing command: {env}=1 cargo rustc --tests -- --emit=mir",
@@ -51,8 +242,8 @@ fn get_mir_file_path(
     let child = std::process::Command::new("cargo")
         .args(vec!["rustc", "--tests", "--", "--emit=mir"])
         .env(common::ENV_SKIP_BUILD, "1")
-        .env("CARGO_TARGET_DIR", &mir_tmp_directory)
-        .current_dir(crate_directory)
+        .env("CARGO_TARGET_DIR", mir_tmp_directory)
+        .current_dir(package_directory)
         .stderr(std::process::Stdio::piped())
         .spawn()
         .expect("Failed to generate MIR file.");
@@ -67,21 +258,52 @@ fn get_mir_file_path(
     }
 
     let deps_directory = mir_tmp_directory.join("debug/deps");
-    let entry = walkdir::WalkDir::new(&deps_directory)
-        .into_iter()
-        .filter_map(Result::ok)
-        .filter(|e| e.file_type().is_file())
-        .filter(|e| e.path().extension().and_then(|x| x.to_str()) == Some("mir"))
-        .next();
-    let entry = entry.ok_or(format_err!("Failed to find MIR file."))?;
+    let path = find_mir_file(&deps_directory, crate_names)?
+        .ok_or(format_err!("Failed to find MIR file."))?;
 
-    let mir_file_path = out_directory.join("mir");
-    std::fs::copy(entry.path(), &mir_file_path)?;
+    let mir_file_path = mir_tmp_directory.join("mir");
+    std::fs::copy(&path, &mir_file_path)?;
 
     log::debug!("Found MIR file: {}", mir_file_path.display());
     Ok(Some(mir_file_path))
 }
 
+/// Returns the most recently written MIR file of one of the crates in `crate_names`.
+///
+/// MIR files are named `<crate name>-<hash>.mir`. The directory keeps the files of earlier builds, so the newest file
+/// belongs to the build which just finished.
+fn find_mir_file(
+    deps_directory: &std::path::Path,
+    crate_names: &[String],
+) -> Result<Option<std::path::PathBuf>> {
+    let mut newest: Option<(std::time::SystemTime, std::path::PathBuf)> = None;
+    for entry in walkdir::WalkDir::new(deps_directory)
+        .max_depth(1)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+    {
+        let path = entry.path();
+        if path.extension().and_then(|x| x.to_str()) != Some("mir") {
+            continue;
+        }
+        let stem = path
+            .file_stem()
+            .and_then(|x| x.to_str())
+            .unwrap_or_default();
+        let crate_name = stem.rsplit_once('-').map_or(stem, |(name, _hash)| name);
+        if !crate_names.iter().any(|name| name == crate_name) {
+            continue;
+        }
+        let modified = entry.metadata()?.modified()?;
+        match &newest {
+            Some((time, _)) if *time >= modified => {}
+            _ => newest = Some((modified, path.to_path_buf())),
+        }
+    }
+    Ok(newest.map(|(_, path)| path))
+}
+
 pub fn analyse(code_meta: &CodeMeta) -> Result<Vec<specification::Specification>> {
     log::debug!("Analyzing code metadata.");
     let mut interface_specs = Vec::<_>::new();
@@ -99,6 +321,97 @@ pub fn analyse(code_meta: &CodeMeta) -> Result<Vec<specification::Specification>
     Ok(interface_specs)
 }
 
+/// Returns the specifications of explicitly listed structure types.
+pub fn analyse_specs(specs: &[Spec]) -> Result<Vec<specification::Specification>> {
+    let mut interface_specs = Vec::<_>::new();
+    for spec in specs {
+        log::debug!("Adding listed type: {}", spec.type_name());
+        let specifications = analyse(&CodeMeta { mir: spec.mir() })?;
+        if specifications.is_empty() {
+            return Err(format_err!(
+                "No SDSL structure matches listed type: {}",
+                spec.type_name()
+            ));
+        }
+        interface_specs.extend(specifications);
+    }
+    Ok(interface_specs)
+}
+
+/// Fail if SDSL structure types used in the code are missing from the manifest of the generated library.
+///
+/// Structure types are found by path alone, so that types which analysis fails to match are reported as well. Types
+/// with generic parameters, as found in generic functions, are skipped: their instances are checked where they occur.
+pub fn check_coverage(
+    code_meta: &CodeMeta,
+    instantiations: &[manifest::Instantiation],
+) -> Result<()> {
+    let ids: std::collections::BTreeSet<_> = instantiations
+        .iter()
+        .map(|instantiation| instantiation.id())
+        .collect();
+
+    let paths: Vec<_> = meta::get_metas()?.iter().map(|meta| meta.path()).collect();
+    let type_regex = regex::Regex::new(r": ?&?(mut )?(?P<type>sdsl::[^;\n]+);")?;
+    let mut types = std::collections::BTreeSet::<_>::new();
+    for captures in type_regex.captures_iter(&code_meta.mir) {
+        let type_name = captures["type"].to_string();
+        let path = type_name.split('<').next().unwrap_or_default();
+        if paths.iter().any(|p| p == path) {
+            types.insert(type_name);
+        }
+    }
+
+    let mut missing = Vec::<_>::new();
+    for type_name in types {
+        if !is_concrete(&type_name)? {
+            log::debug!("Skipping coverage check of generic type: {}", type_name);
+            continue;
+        }
+        let type_specifications = analyse(&CodeMeta {
+            mir: format!(": {};", type_name),
+        })?;
+        let mut covered = !type_specifications.is_empty();
+        for type_specification in type_specifications {
+            covered &= ids.contains(specification::get_id(&type_specification.c_code)?.as_str());
+        }
+        if !covered {
+            missing.push(rust_type_name(&type_name)?);
+        }
+    }
+
+    if !missing.is_empty() {
+        let specs: Vec<_> = missing
+            .iter()
+            .map(|type_name| format!("sdsl::spec!({})", type_name))
+            .collect();
+        return Err(format_err!(
+            "SDSL structure types are used but missing from the generated library: {}. \
+             Register them with sdsl::build_with(&[{}]).",
+            missing.join(", "),
+            specs.join(", ")
+        ));
+    }
+    Ok(())
+}
+
+/// Returns true if all generic parameters of an MIR type name are SDSL types or integers.
+fn is_concrete(mir_type_name: &str) -> Result<bool> {
+    let parameter_regex = regex::Regex::new(r"sdsl::(\w+::)*\w+|\d+_(u|i)(8|16|32|64|128|size)")?;
+    Ok(parameter_regex
+        .replace_all(mir_type_name, "")
+        .chars()
+        .all(|c| c == '<' || c == '>' || c == ',' || c.is_whitespace()))
+}
+
+/// Removes the integer suffixes, such as `_u16`, which MIR adds to generic parameters.
+fn rust_type_name(mir_type_name: &str) -> Result<String> {
+    let suffix_regex = regex::Regex::new(r"(?P<value>\d+)_(u|i)(8|16|32|64|128|size)")?;
+    Ok(suffix_regex
+        .replace_all(mir_type_name, "$value")
+        .into_owned())
+}
+
 fn default_specification(
     code_meta: &CodeMeta,
     meta: &Box<dyn meta::common::Meta>,
@@ -179,11 +492,194 @@ fn handle_sdsl_type(parameter_value: &str) -> Result<specification::Specificatio
     Ok(specification)
 }
 
-// #[test]
-// fn foo() -> Result<()> {
-//     let x = analyse(&CodeMeta {
-//         mir: "        let _44: sdsl::wavelet_trees::WtHuff; ".to_string(),
-//     })?;
-//     println!("{:#?}", x);
-//     Ok(())
-// }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_analyse_specs_matches_mir() -> Result<()> {
+        let specs = [crate::spec!(
+            sdsl::bit_vectors::RrrVector<sdsl::int_vectors::IntVector<0>, 63, 32>
+        )];
+        let result = analyse_specs(&specs)?;
+        let expected = analyse(&CodeMeta {
+            mir: "        let _1: sdsl::bit_vectors::RrrVector<sdsl::int_vectors::IntVector<0_u8>, 63_u16, 32_u16>;"
+                .to_string(),
+        })?;
+        assert!(!expected.is_empty());
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyse_specs_unknown_type() {
+        let specs = [crate::spec!(sdsl::bit_vectors::Unknown)];
+        let result = analyse_specs(&specs);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_rust_type_name() -> Result<()> {
+        let result = rust_type_name("sdsl::int_vectors::IntVector<0_u8>")?;
+        let expected = "sdsl::int_vectors::IntVector<0>";
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    fn manifest_of(specs: &[Spec]) -> Result<Vec<manifest::Instantiation>> {
+        manifest::parse_source(&manifest::source(&analyse_specs(specs)?)?)
+    }
+
+    #[test]
+    fn test_check_coverage_reports_missing_type() -> Result<()> {
+        let instantiations = manifest_of(&[crate::spec!(sdsl::int_vectors::IntVector<0>)])?;
+        let code_meta = CodeMeta {
+            mir: "        let _1: sdsl::int_vectors::IntVector<0_u8>;\n\
+                  let _2: sdsl::bit_vectors::RrrVector<sdsl::int_vectors::IntVector<0_u8>, 63_u16, 32_u16>;\n"
+                .to_string(),
+        };
+        let result = check_coverage(&code_meta, &instantiations).map_err(|e| e.to_string());
+        let expected = Err(
+            "SDSL structure types are used but missing from the generated library: \
+             sdsl::bit_vectors::RrrVector<sdsl::int_vectors::IntVector<0>, 63, 32>. \
+             Register them with sdsl::build_with(&[sdsl::spec!(\
+             sdsl::bit_vectors::RrrVector<sdsl::int_vectors::IntVector<0>, 63, 32>)])."
+                .to_string(),
+        );
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_coverage_accepts_compiled_types() -> Result<()> {
+        let instantiations = manifest_of(&[crate::spec!(
+            sdsl::bit_vectors::RrrVector<sdsl::int_vectors::IntVector<0>, 63, 32>
+        )])?;
+        let code_meta = CodeMeta {
+            mir: "        let _1: sdsl::bit_vectors::RrrVector<sdsl::int_vectors::IntVector<0_u8>, 63_u16, 32_u16>;\n\
+                  let _2: sdsl::int_vectors::IntVector<WIDTH>;\n"
+                .to_string(),
+        };
+        check_coverage(&code_meta, &instantiations)
+    }
+
+    /// The id computed at runtime by `Id::id` must be among the ids which analysis compiles into the library.
+    fn check_id_matches_analysis<T: crate::interface::common::Code>(spec: Spec) -> Result<()> {
+        let ids = analyse_specs(std::slice::from_ref(&spec))?
+            .iter()
+            .map(|specification| specification::get_id(&specification.c_code))
+            .collect::<Result<Vec<_>>>()?;
+        let id = specification::get_id(&T::c_code()?)?;
+        assert!(
+            ids.contains(&id),
+            "{}: {} not in {:?}",
+            spec.type_name(),
+            id,
+            ids
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_id_matches_analysis() -> Result<()> {
+        use crate::interface::{bit_vectors, int_vector, wavelet_trees};
+        check_id_matches_analysis::<int_vector::IntVector<0>>(crate::spec!(
+            sdsl::int_vectors::IntVector<0>
+        ))?;
+        check_id_matches_analysis::<int_vector::IntVector<16>>(crate::spec!(
+            sdsl::int_vectors::IntVector<16>
+        ))?;
+        check_id_matches_analysis::<bit_vectors::bit_vector::BitVector>(crate::spec!(
+            sdsl::bit_vectors::BitVector
+        ))?;
+        check_id_matches_analysis::<
+            bit_vectors::rrr_vector::RrrVector<int_vector::IntVector<0>, 63, 32>,
+        >(crate::spec!(
+            sdsl::bit_vectors::RrrVector<sdsl::int_vectors::IntVector<0>, 63, 32>
+        ))?;
+        check_id_matches_analysis::<wavelet_trees::wt_int::WtInt>(crate::spec!(
+            sdsl::wavelet_trees::WtInt
+        ))?;
+        check_id_matches_analysis::<wavelet_trees::wt_huff::WtHuff>(crate::spec!(
+            sdsl::wavelet_trees::WtHuff
+        ))?;
+        Ok(())
+    }
+
+    fn package(id: &str, source: Option<&str>, dependencies: &[&str]) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "name": id,
+            "source": source,
+            "manifest_path": format!("/workspace/{}/Cargo.toml", id),
+            "dependencies": dependencies
+                .iter()
+                .map(|name| serde_json::json!({ "name": name }))
+                .collect::<Vec<_>>(),
+            "targets": [{ "name": id }],
+        })
+    }
+
+    fn node(id: &str, deps: &[(&str, Option<&str>)]) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "deps": deps
+                .iter()
+                .map(|(pkg, kind)| serde_json::json!({ "pkg": pkg, "dep_kinds": [{ "kind": kind }] }))
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    #[test]
+    fn test_sdsl_packages_walks_resolve_graph() -> Result<()> {
+        let metadata: Metadata = serde_json::from_value(serde_json::json!({
+            "packages": [
+                package("app", None, &["lib-a", "sdsl"]),
+                package("lib-a", None, &["lib-b", "sdsl"]),
+                package("lib-b", None, &["sdsl"]),
+                package("unrelated", None, &["sdsl"]),
+                package("tool", None, &["sdsl"]),
+                package("remote", Some("registry+https://github.com/rust-lang/crates.io-index"), &["sdsl"]),
+                package("sdsl", None, &[]),
+            ],
+            "resolve": {
+                "nodes": [
+                    node("app", &[("lib-a", None), ("tool", Some("build")), ("remote", None), ("sdsl", None)]),
+                    node("lib-a", &[("lib-b", None), ("sdsl", None)]),
+                    node("lib-b", &[("unrelated", Some("dev")), ("sdsl", None)]),
+                    node("unrelated", &[("sdsl", None)]),
+                    node("tool", &[("sdsl", None)]),
+                    node("remote", &[("sdsl", None)]),
+                    node("sdsl", &[]),
+                ],
+            },
+        }))?;
+        let packages = sdsl_packages(metadata, std::path::Path::new("/workspace/app/Cargo.toml"))?;
+        let result: Vec<_> = packages
+            .iter()
+            .map(|package| package.name.as_str())
+            .collect();
+        let expected = vec!["app", "lib-a", "lib-b"];
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_mir_file_selects_newest_of_crate() -> Result<()> {
+        let directory = std::env::temp_dir().join(format!("sdsl-test-mir-{}", std::process::id()));
+        std::fs::create_dir_all(&directory)?;
+        std::fs::write(directory.join("my_crate-0000.mir"), "old")?;
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        std::fs::write(directory.join("my_crate-1111.mir"), "new")?;
+        std::fs::write(directory.join("my_crate-1111.rmeta"), "")?;
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        std::fs::write(directory.join("other_crate-2222.mir"), "other")?;
+
+        let path = find_mir_file(&directory, &["my_crate".to_string()])?;
+        let result = path.map(std::fs::read_to_string).transpose()?;
+        std::fs::remove_dir_all(&directory)?;
+        let expected = Some("new".to_string());
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,208 @@
+use anyhow::Result;
+
+use super::{sdsl_c, Spec};
+
+/// A builder for the SDSL interface backend with compiler options.
+///
+/// Options are passed to the CMake configuration of the generated SDSL C library.
+///
+/// # Example
+/// ```ignore
+/// // build.rs
+/// fn main() {
+///     match sdsl::Build::new()
+///         .opt_level(3)
+///         .cpu_feature("sse4.2")
+///         .cpu_feature("bmi2")
+///         .compile()
+///     {
+///         Ok(_) => {}
+///         Err(e) => panic!("Error: {}", e),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Build {
+    specs: Vec<Spec>,
+    opt_level: Option<String>,
+    target_cpu: Option<String>,
+    cpu_features: Vec<String>,
+    ndebug: Option<bool>,
+    defines: Vec<(String, Option<String>)>,
+    debug_assertions: bool,
+}
+
+impl Build {
+    /// Construct a builder with the default options of [`build`](crate::build).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Include a structure type, see [`build_with`](crate::build_with).
+    pub fn spec(&mut self, spec: Spec) -> &mut Self {
+        self.specs.push(spec);
+        self
+    }
+
+    /// Include structure types, see [`build_with`](crate::build_with).
+    pub fn specs(&mut self, specs: &[Spec]) -> &mut Self {
+        self.specs.extend(specs.iter().cloned());
+        self
+    }
+
+    /// Set the optimization level, passed as `-O<level>`.
+    pub fn opt_level(&mut self, level: u32) -> &mut Self {
+        self.opt_level = Some(level.to_string());
+        self
+    }
+
+    /// Set the optimization level from a string, such as `"s"` for `-Os`.
+    pub fn opt_level_str(&mut self, level: &str) -> &mut Self {
+        self.opt_level = Some(level.to_string());
+        self
+    }
+
+    /// Set the target CPU, passed as `-march=<cpu>`, such as `"native"`.
+    pub fn target_cpu(&mut self, cpu: &str) -> &mut Self {
+        self.target_cpu = Some(cpu.to_string());
+        self
+    }
+
+    /// Enable a target CPU feature, passed as `-m<feature>`, such as `"sse4.2"` or `"bmi2"`.
+    ///
+    /// SDSL uses hardware popcount and bit manipulation instructions where available.
+    pub fn cpu_feature(&mut self, feature: &str) -> &mut Self {
+        self.cpu_features.push(feature.to_string());
+        self
+    }
+
+    /// Define `NDEBUG`, which disables assertions in SDSL.
+    pub fn ndebug(&mut self, ndebug: bool) -> &mut Self {
+        self.ndebug = Some(ndebug);
+        self
+    }
+
+    /// Define a preprocessor macro, passed as `-D<name>` or `-D<name>=<value>`.
+    pub fn define<'a, V: Into<Option<&'a str>>>(&mut self, name: &str, value: V) -> &mut Self {
+        self.defines
+            .push((name.to_string(), value.into().map(|v| v.to_string())));
+        self
+    }
+
+    /// Enable assertions in SDSL and in the C++ standard library.
+    ///
+    /// `NDEBUG` is not defined unless [`ndebug`](Self::ndebug) is set.
+    pub fn debug_assertions(&mut self, enabled: bool) -> &mut Self {
+        self.debug_assertions = enabled;
+        self
+    }
+
+    /// Build the SDSL interface backend.
+    ///
+    /// This function should be executed in the project's build script, see [`build`](crate::build).
+    pub fn compile(&self) -> Result<()> {
+        super::build_interface(&self.specs, &self.compile_options())
+    }
+
+    fn compile_options(&self) -> sdsl_c::specification::CompileOptions {
+        let cargo_opt_level = std::env::var("OPT_LEVEL").ok();
+        self.compile_options_for(cargo_opt_level.as_deref())
+    }
+
+    /// Returns the compile options for cargo's optimization level, given by the `OPT_LEVEL` env variable.
+    fn compile_options_for(
+        &self,
+        cargo_opt_level: Option<&str>,
+    ) -> sdsl_c::specification::CompileOptions {
+        let mut cxx_flags = Vec::<String>::new();
+        if let Some(cpu) = &self.target_cpu {
+            cxx_flags.push(format!("-march={}", cpu));
+        }
+        for feature in &self.cpu_features {
+            cxx_flags.push(format!("-m{}", feature));
+        }
+        for (name, value) in &self.defines {
+            match value {
+                Some(value) => cxx_flags.push(format!("-D{}={}", name, value)),
+                None => cxx_flags.push(format!("-D{}", name)),
+            }
+        }
+        if self.debug_assertions {
+            cxx_flags.push("-D_GLIBCXX_ASSERTIONS".to_string());
+        }
+
+        // The build type flags follow the general flags, so they are replaced to control -O and NDEBUG. The
+        // optimization level defaults to cargo's, so that setting only NDEBUG keeps the level of the cargo profile.
+        let build_type_flags =
+            if self.opt_level.is_some() || self.ndebug.is_some() || self.debug_assertions {
+                let opt_level = match (&self.opt_level, cargo_opt_level) {
+                    (Some(opt_level), _) => Some(opt_level.as_str()),
+                    // Not all C++ compilers support -Oz.
+                    (None, Some("z")) => Some("s"),
+                    (None, cargo_opt_level) => cargo_opt_level,
+                };
+                let mut flags = Vec::<String>::new();
+                if let Some(opt_level) = opt_level {
+                    flags.push(format!("-O{}", opt_level));
+                }
+                if self.ndebug.unwrap_or(!self.debug_assertions) {
+                    flags.push("-DNDEBUG".to_string());
+                }
+                Some(flags.join(" "))
+            } else {
+                None
+            };
+
+        sdsl_c::specification::CompileOptions {
+            cxx_flags,
+            build_type_flags,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compile_options_default() {
+        let result = Build::new().compile_options_for(Some("3"));
+        let expected = sdsl_c::specification::CompileOptions::default();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_compile_options_opt_level() {
+        let result = Build::new()
+            .opt_level(2)
+            .cpu_feature("sse4.2")
+            .compile_options_for(Some("0"));
+        let expected = sdsl_c::specification::CompileOptions {
+            cxx_flags: vec!["-msse4.2".to_string()],
+            build_type_flags: Some("-O2 -DNDEBUG".to_string()),
+        };
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_compile_options_debug_assertions_keep_cargo_opt_level() {
+        let result = Build::new()
+            .debug_assertions(true)
+            .compile_options_for(Some("0"));
+        let expected = sdsl_c::specification::CompileOptions {
+            cxx_flags: vec!["-D_GLIBCXX_ASSERTIONS".to_string()],
+            build_type_flags: Some("-O0".to_string()),
+        };
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_compile_options_ndebug_keeps_cargo_opt_level() {
+        let result = Build::new().ndebug(true).compile_options_for(Some("z"));
+        let expected = sdsl_c::specification::CompileOptions {
+            cxx_flags: vec![],
+            build_type_flags: Some("-Os -DNDEBUG".to_string()),
+        };
+        assert_eq!(result, expected);
+    }
+}
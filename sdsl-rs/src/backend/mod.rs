@@ -1,13 +1,19 @@
 use anyhow::{format_err, Result};
 
 mod analyse;
+mod build;
 mod common;
 pub mod sdsl_c;
+mod spec;
+
+pub use build::Build;
+pub use spec::Spec;
 
 /// Build the SDSL interface backend.
 ///
 /// This function should be executed in the project's [build script](https://doc.rust-lang.org/cargo/reference/build-scripts.html).
 /// It analyses the project's code base and builds a suitable SDSL interface backend.
+/// Local crates which depend on SDSL, such as workspace members and path dependencies, are analysed as well.
 ///
 /// # Example
 /// ```ignore
@@ -20,7 +26,38 @@ pub mod sdsl_c;
 /// }
 /// ```
 /// A working example can be found [here](https://github.com/sdsl-rs/sdsl-rs/blob/master/examples/build.rs).
+///
+/// Use [`Build`](crate::Build) to set compiler options.
 pub fn build() -> Result<()> {
+    build_with(&[])
+}
+
+/// Build the SDSL interface backend, including the given structure types.
+///
+/// The structure types found by analysing the project's code base are included as well.
+/// Listing types explicitly helps where analysis misses them, for example types used only in crates from a registry.
+///
+/// # Example
+/// ```ignore
+/// // build.rs
+/// fn main() {
+///     let specs = [sdsl::spec!(
+///         sdsl::bit_vectors::RrrVector<sdsl::int_vectors::IntVector<0>, 63, 32>
+///     )];
+///     match sdsl::build_with(&specs) {
+///         Ok(_) => {}
+///         Err(e) => panic!("Error: {}", e),
+///     };
+/// }
+/// ```
+pub fn build_with(specs: &[Spec]) -> Result<()> {
+    Build::new().specs(specs).compile()
+}
+
+fn build_interface(
+    specs: &[Spec],
+    compile_options: &sdsl_c::specification::CompileOptions,
+) -> Result<()> {
     simple_logger::SimpleLogger::new().init()?;
 
     if common::skip_build() {
@@ -32,18 +69,49 @@ pub fn build() -> Result<()> {
 
     let crate_directory = get_crate_directory()?;
     let code_meta = analyse::setup(&crate_directory, &out_directory)?;
-
-    let specifications = if let Some(code_meta) = code_meta {
-        analyse::analyse(&code_meta)?
-    } else {
+    if code_meta.is_none() && specs.is_empty() {
         log::debug!("Failed to generate code metadata for analysis. Exiting SDSL build.");
         return Ok(());
-    };
+    }
+
+    let mut specifications = analyse::analyse_specs(specs)?;
+    if let Some(code_meta) = &code_meta {
+        specifications.extend(analyse::analyse(code_meta)?);
+    } else {
+        log::debug!("Failed to generate code metadata for analysis.");
+    }
+    // Crates analysed together often share structure types.
+    let mut unique_specifications = Vec::<_>::new();
+    for specification in specifications {
+        if !unique_specifications.contains(&specification) {
+            unique_specifications.push(specification);
+        }
+    }
+    let specifications = unique_specifications;
 
+    let sdsl_lite = sdsl_c::sdsl_lite::find(&out_directory)?;
     let template_directory = sdsl_c::template::setup(&out_directory)?;
-    let interface_directory =
-        sdsl_c::specification::setup(&specifications, &template_directory, &out_directory)?;
-    let lib_path = sdsl_c::specification::compile(&interface_directory)?;
+    let interface_directory = sdsl_c::specification::setup(
+        &specifications,
+        &template_directory,
+        &out_directory,
+        &sdsl_lite,
+    )?;
+    if let Some(code_meta) = &code_meta {
+        let manifest_path = interface_directory
+            .join("src")
+            .join(sdsl_c::manifest::SOURCE_FILE_NAME);
+        let instantiations =
+            sdsl_c::manifest::parse_source(&std::fs::read_to_string(&manifest_path)?)?;
+        analyse::check_coverage(code_meta, &instantiations)?;
+    }
+    let lib_path = sdsl_c::specification::compile(
+        &specifications,
+        &interface_directory,
+        &out_directory,
+        &sdsl_lite,
+        compile_options,
+    )?;
     log::info!("Compilation complete. Library path: {}", lib_path.display());
 
     println!("cargo:rerun-if-changed=./src");
@@ -0,0 +1,188 @@
+use anyhow::Result;
+
+use super::{sdsl_lite, specification, template};
+
+/// Environment variable with the directory of the compiled library cache. Caching is disabled if not set.
+pub static ENV_CACHE_DIR: &str = "SDSL_CACHE_DIR";
+
+/// Environment variables which affect the compiled library.
+static ENV_COMPILER_FLAGS: &[&str] = &[
+    "TARGET",
+    "PROFILE",
+    "OPT_LEVEL",
+    "DEBUG",
+    "CC",
+    "CXX",
+    "CFLAGS",
+    "CXXFLAGS",
+];
+
+/// A cache of compiled libraries, shared across crates and target directories.
+///
+/// Entries are keyed by the specification ids, the template, the compiler version and the compiler flags.
+pub struct Cache {
+    entry_directory: std::path::PathBuf,
+}
+
+impl Cache {
+    /// Returns the cache entry for the given specifications, or None if caching is disabled.
+    pub fn new(
+        specifications: &[specification::Specification],
+        sdsl_lite: &Option<sdsl_lite::SdslLite>,
+        compile_options: &specification::CompileOptions,
+    ) -> Result<Option<Self>> {
+        println!("cargo:rerun-if-env-changed={}", ENV_CACHE_DIR);
+        let cache_directory = match std::env::var_os(ENV_CACHE_DIR) {
+            Some(directory) => std::path::PathBuf::from(directory),
+            None => return Ok(None),
+        };
+        let key = get_key(specifications, sdsl_lite, compile_options)?;
+        Ok(Some(Self {
+            entry_directory: cache_directory.join(key),
+        }))
+    }
+
+    /// Copy the cached library files into `lib_directory`.
+    ///
+    /// Returns false if the cache has no entry.
+    pub fn restore(&self, lib_directory: &std::path::Path) -> Result<bool> {
+        if !self.entry_directory.exists() {
+            return Ok(false);
+        }
+        log::info!(
+            "Using cached SDSL C library: {}",
+            self.entry_directory.display()
+        );
+        std::fs::create_dir_all(lib_directory)?;
+        let mut options = fs_extra::dir::CopyOptions::new();
+        options.overwrite = true;
+        options.content_only = true;
+        fs_extra::dir::copy(&self.entry_directory, lib_directory, &options)?;
+        Ok(true)
+    }
+
+    /// Add the library files in `lib_directory` to the cache.
+    pub fn store(&self, lib_directory: &std::path::Path) -> Result<()> {
+        if self.entry_directory.exists() {
+            return Ok(());
+        }
+        log::debug!("Caching SDSL C library: {}", self.entry_directory.display());
+        // Copied to a temporary directory first, so that concurrent builds never see a partial entry.
+        let tmp_directory = self
+            .entry_directory
+            .with_extension(format!("tmp-{}", std::process::id()));
+        std::fs::create_dir_all(&tmp_directory)?;
+        let mut options = fs_extra::dir::CopyOptions::new();
+        options.overwrite = true;
+        options.content_only = true;
+        fs_extra::dir::copy(lib_directory, &tmp_directory, &options)?;
+        if std::fs::rename(&tmp_directory, &self.entry_directory).is_err() {
+            // Another build stored the same entry.
+            std::fs::remove_dir_all(&tmp_directory)?;
+        }
+        Ok(())
+    }
+}
+
+fn get_key(
+    specifications: &[specification::Specification],
+    sdsl_lite: &Option<sdsl_lite::SdslLite>,
+    compile_options: &specification::CompileOptions,
+) -> Result<String> {
+    let mut ids = specifications
+        .iter()
+        .map(|specification| specification::get_id(&specification.c_code))
+        .collect::<Result<Vec<_>>>()?;
+    ids.sort();
+    ids.dedup();
+
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(env!("CARGO_PKG_VERSION").as_bytes());
+    hasher.update(template::ARCHIVE);
+    for (path, contents) in template::EXTENSIONS {
+        hasher.update(path.as_bytes());
+        hasher.update(contents.as_bytes());
+    }
+    hasher.update(compiler_version().as_bytes());
+    for id in ids {
+        hasher.update(id.as_bytes());
+    }
+    for variable in ENV_COMPILER_FLAGS {
+        let value = std::env::var(variable).unwrap_or_default();
+        hasher.update(format!("{}={}\n", variable, value).as_bytes());
+    }
+    hasher.update(format!("static={}\n", cfg!(feature = "static")).as_bytes());
+    hasher.update(format!("{:?}", sdsl_lite).as_bytes());
+    hasher.update(format!("{:?}", compile_options).as_bytes());
+    let hash = hasher.finalize().to_hex().as_str().to_string();
+    Ok(hash.chars().take(32).collect())
+}
+
+/// Returns the output of `$CXX --version`, or an empty string if the compiler can not be run.
+///
+/// The compiler is found like CMake does: the `CXX` env variable, otherwise `c++`.
+fn compiler_version() -> String {
+    let compiler = std::env::var("CXX").unwrap_or_else(|_| "c++".to_string());
+    std::process::Command::new(compiler)
+        .arg("--version")
+        .stderr(std::process::Stdio::null())
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_directory(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("sdsl-test-{}-{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_get_key_is_stable() -> Result<()> {
+        let compile_options = specification::CompileOptions::default();
+        let result = get_key(&[], &None, &compile_options)?;
+        let expected = get_key(&[], &None, &compile_options)?;
+        assert_eq!(result, expected);
+        assert_eq!(result.len(), 32);
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_key_depends_on_compile_options() -> Result<()> {
+        let compile_options = specification::CompileOptions {
+            cxx_flags: vec!["-msse4.2".to_string()],
+            build_type_flags: None,
+        };
+        let result = get_key(&[], &None, &compile_options)?;
+        let default_key = get_key(&[], &None, &specification::CompileOptions::default())?;
+        assert_ne!(result, default_key);
+        Ok(())
+    }
+
+    #[test]
+    fn test_store_and_restore() -> Result<()> {
+        let directory = temp_directory("cache");
+        let lib_directory = directory.join("lib");
+        std::fs::create_dir_all(&lib_directory)?;
+        std::fs::write(lib_directory.join("libsdsl_c.so"), "library")?;
+        let cache = Cache {
+            entry_directory: directory.join("cache").join("key"),
+        };
+        std::fs::create_dir_all(directory.join("cache"))?;
+
+        let restore_directory = directory.join("restored");
+        let missed = cache.restore(&restore_directory)?;
+        cache.store(&lib_directory)?;
+        let restored = cache.restore(&restore_directory)?;
+        let result = std::fs::read_to_string(restore_directory.join("libsdsl_c.so"))?;
+        std::fs::remove_dir_all(&directory)?;
+
+        assert!(!missed);
+        assert!(restored);
+        let expected = "library";
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
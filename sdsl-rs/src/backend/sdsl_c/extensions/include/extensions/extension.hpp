@@ -0,0 +1,58 @@
+// Helpers for the C functions which sdsl-rs adds to the sdsl-c template.
+//
+// Each extension source defines EXTENSION_ID and STRUCTURE before including this header.
+#include <cstddef>
+#include <cstdint>
+#include <cstring>
+#include <exception>
+#include <string>
+
+#define SDSL_C_CONCAT_(a, b) a##b
+#define SDSL_C_CONCAT(a, b) SDSL_C_CONCAT_(a, b)
+
+// Name of the C function `name` for the structure of the including source file.
+#define SDSL_C_FUNCTION(name) SDSL_C_CONCAT(name, EXTENSION_ID)
+
+namespace sdsl_c {
+// Stores `message` in the thread-local slot read by take_last_error, see error.cpp.
+void set_last_error(const char *message);
+}
+
+namespace {
+typedef STRUCTURE structure_type;
+
+// Returns the structure behind a pointer created by the sdsl-c template.
+inline structure_type &structure(void *ptr) {
+    return *static_cast<structure_type *>(ptr);
+}
+
+// Returns a copy of `string` which the caller releases with `delete[]`.
+inline char *copy_string(const std::string &string) {
+    char *copy = new char[string.size() + 1];
+    std::memcpy(copy, string.c_str(), string.size() + 1);
+    return copy;
+}
+
+// Calls `function` and returns its result, or `failed` if it throws. The exception message is stored in the
+// last-error slot instead, since C++ exceptions must not unwind into Rust.
+template <typename Result, typename Function>
+inline Result guard(Result failed, Function function) {
+    try {
+        return function();
+    } catch (const std::exception &exception) {
+        sdsl_c::set_last_error(exception.what());
+    } catch (...) {
+        sdsl_c::set_last_error("unknown exception");
+    }
+    return failed;
+}
+
+// Calls `function`, storing the message of a thrown exception in the last-error slot.
+template <typename Function>
+inline void guard(Function function) {
+    guard(false, [&] {
+        function();
+        return true;
+    });
+}
+}
@@ -0,0 +1,102 @@
+#include <sdsl/int_vector.hpp>
+
+#define EXTENSION_ID _id
+#define STRUCTURE sdsl::int_vector<0>
+#include "extensions/extension.hpp"
+
+extern "C" void SDSL_C_FUNCTION(bit_vector_get_range)(void *ptr, size_t start, size_t len, uint8_t *values) {
+    const auto &vector = structure(ptr);
+    for (size_t i = 0; i < len; ++i) {
+        values[i] = vector[start + i];
+    }
+}
+
+extern "C" void SDSL_C_FUNCTION(bit_vector_set_range)(void *ptr, size_t start, size_t len, const uint8_t *values) {
+    auto &vector = structure(ptr);
+    for (size_t i = 0; i < len; ++i) {
+        vector[start + i] = values[i];
+    }
+}
+
+namespace {
+// Number of 64 bit words which hold the bits of `vector`.
+inline size_t word_count(const structure_type &vector) {
+    return (vector.bit_size() + 63) >> 6;
+}
+
+// Mask of the bits of the last word which belong to `vector`. Bits past the end may hold stale values.
+inline uint64_t last_word_mask(const structure_type &vector) {
+    const size_t offset = vector.bit_size() & 0x3F;
+    return offset == 0 ? ~0ULL : (1ULL << offset) - 1;
+}
+
+// Replaces each word of the vector behind `ptr` by `op` applied to it and the word of the vector behind `other`.
+template <typename t_op> inline void combine_words(void *ptr, void *other, t_op op) {
+    auto &vector = structure(ptr);
+    const auto &other_vector = structure(other);
+    uint64_t *data = vector.data();
+    const uint64_t *other_data = other_vector.data();
+    for (size_t i = 0; i < word_count(vector); ++i) {
+        data[i] = op(data[i], other_data[i]);
+    }
+}
+}
+
+extern "C" void SDSL_C_FUNCTION(bit_vector_bit_and_assign)(void *ptr, void *other) {
+    combine_words(ptr, other, [](uint64_t a, uint64_t b) { return a & b; });
+}
+
+extern "C" void SDSL_C_FUNCTION(bit_vector_bit_or_assign)(void *ptr, void *other) {
+    combine_words(ptr, other, [](uint64_t a, uint64_t b) { return a | b; });
+}
+
+extern "C" void SDSL_C_FUNCTION(bit_vector_bit_xor_assign)(void *ptr, void *other) {
+    combine_words(ptr, other, [](uint64_t a, uint64_t b) { return a ^ b; });
+}
+
+extern "C" void SDSL_C_FUNCTION(bit_vector_and_not_assign)(void *ptr, void *other) {
+    combine_words(ptr, other, [](uint64_t a, uint64_t b) { return a & ~b; });
+}
+
+extern "C" size_t SDSL_C_FUNCTION(bit_vector_count_ones)(void *ptr) {
+    const auto &vector = structure(ptr);
+    const uint64_t *data = vector.data();
+    const size_t words = word_count(vector);
+    size_t count = 0;
+    for (size_t i = 0; i + 1 < words; ++i) {
+        count += sdsl::bits::cnt(data[i]);
+    }
+    if (words > 0) {
+        count += sdsl::bits::cnt(data[words - 1] & last_word_mask(vector));
+    }
+    return count;
+}
+
+extern "C" bool SDSL_C_FUNCTION(bit_vector_is_subset_of)(void *ptr, void *other) {
+    const auto &vector = structure(ptr);
+    const uint64_t *data = vector.data();
+    const uint64_t *other_data = structure(other).data();
+    const size_t words = word_count(vector);
+    for (size_t i = 0; i < words; ++i) {
+        uint64_t outside = data[i] & ~other_data[i];
+        if (i + 1 == words) {
+            outside &= last_word_mask(vector);
+        }
+        if (outside != 0) {
+            return false;
+        }
+    }
+    return true;
+}
+
+extern "C" void *SDSL_C_FUNCTION(bit_vector_try_create)(size_t size, size_t default_value) {
+    return guard<void *>(nullptr, [&] { return new structure_type(size, default_value); });
+}
+
+extern "C" void *SDSL_C_FUNCTION(bit_vector_try_copy)(void *ptr) {
+    return guard<void *>(nullptr, [&] { return new structure_type(structure(ptr)); });
+}
+
+extern "C" void SDSL_C_FUNCTION(bit_vector_try_resize)(void *ptr, size_t size) {
+    guard([&] { structure(ptr).resize(size); });
+}
@@ -0,0 +1,43 @@
+// The thread-local slot in which guarded C functions store the message of a caught C++ exception.
+//
+// Shared by all structures, so this source is not instantiated per structure.
+#include <cstring>
+#include <new>
+
+namespace {
+thread_local char *last_error = nullptr;
+
+// Stored instead of a copy of the message if the copy can not be allocated.
+char out_of_memory[] = "out of memory";
+
+void free_error(char *error) {
+    if (error != out_of_memory) {
+        delete[] error;
+    }
+}
+}
+
+namespace sdsl_c {
+void set_last_error(const char *message) {
+    free_error(last_error);
+    const size_t len = std::strlen(message) + 1;
+    last_error = new (std::nothrow) char[len];
+    if (last_error == nullptr) {
+        last_error = out_of_memory;
+        return;
+    }
+    std::memcpy(last_error, message, len);
+}
+}
+
+// Returns the message of the last caught exception and empties the slot, or null if the slot is empty. The caller
+// releases the message with free_last_error.
+extern "C" char *take_last_error() {
+    char *error = last_error;
+    last_error = nullptr;
+    return error;
+}
+
+extern "C" void free_last_error(char *error) {
+    free_error(error);
+}
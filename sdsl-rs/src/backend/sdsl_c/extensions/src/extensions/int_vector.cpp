@@ -0,0 +1,43 @@
+#include <sdsl/int_vector.hpp>
+
+#define EXTENSION_ID _id
+#define STRUCTURE sdsl::int_vector<0>
+#include "extensions/extension.hpp"
+
+extern "C" void SDSL_C_FUNCTION(int_vector_get_range)(void *ptr, size_t start, size_t len, uint64_t *values) {
+    const auto &vector = structure(ptr);
+    for (size_t i = 0; i < len; ++i) {
+        values[i] = vector[start + i];
+    }
+}
+
+extern "C" void SDSL_C_FUNCTION(int_vector_set_range)(void *ptr, size_t start, size_t len, const uint64_t *values) {
+    auto &vector = structure(ptr);
+    for (size_t i = 0; i < len; ++i) {
+        vector[start + i] = values[i];
+    }
+}
+
+extern "C" void SDSL_C_FUNCTION(int_vector_push_back)(void *ptr, uint64_t value) {
+    guard([&] {
+        auto &vector = structure(ptr);
+        vector.resize(vector.size() + 1);
+        vector[vector.size() - 1] = value;
+    });
+}
+
+extern "C" void *SDSL_C_FUNCTION(int_vector_try_create)(size_t size, size_t default_value, uint8_t width) {
+    return guard<void *>(nullptr, [&] { return new structure_type(size, default_value, width); });
+}
+
+extern "C" void *SDSL_C_FUNCTION(int_vector_try_copy)(void *ptr) {
+    return guard<void *>(nullptr, [&] { return new structure_type(structure(ptr)); });
+}
+
+extern "C" void SDSL_C_FUNCTION(int_vector_try_resize)(void *ptr, size_t size) {
+    guard([&] { structure(ptr).resize(size); });
+}
+
+extern "C" void SDSL_C_FUNCTION(int_vector_try_bit_resize)(void *ptr, size_t size) {
+    guard([&] { structure(ptr).bit_resize(size); });
+}
@@ -0,0 +1,182 @@
+#include <istream>
+#include <ostream>
+#include <sstream>
+#include <streambuf>
+
+#include <sdsl/int_vector.hpp>
+#include <sdsl/io.hpp>
+#include <sdsl/rank_support_v.hpp>
+#include <sdsl/rrr_vector.hpp>
+#include <sdsl/select_support_mcl.hpp>
+#include <sdsl/wavelet_trees.hpp>
+
+#define EXTENSION_ID _id
+#define STRUCTURE sdsl::int_vector<0>
+#include "extensions/extension.hpp"
+
+namespace {
+// Writes `len` bytes to the Rust stream behind `context`. Returns false on failure.
+typedef bool (*write_callback)(void *context, const uint8_t *data, size_t len);
+
+// Reads up to `len` bytes from the Rust stream behind `context`. Returns the number of bytes read, 0 at the end of
+// the stream or on failure.
+typedef size_t (*read_callback)(void *context, uint8_t *data, size_t len);
+
+const size_t stream_buffer_size = 1 << 16;
+
+// Stream buffer which passes its content to a Rust writer, one buffer at a time.
+class writer_buffer : public std::streambuf {
+  public:
+    writer_buffer(void *context, write_callback write) : m_context(context), m_write(write) {
+        setp(m_buffer, m_buffer + stream_buffer_size);
+    }
+
+  protected:
+    int_type overflow(int_type c) override {
+        if (sync() != 0) {
+            return traits_type::eof();
+        }
+        if (!traits_type::eq_int_type(c, traits_type::eof())) {
+            *pptr() = traits_type::to_char_type(c);
+            pbump(1);
+        }
+        return traits_type::not_eof(c);
+    }
+
+    int sync() override {
+        const size_t len = pptr() - pbase();
+        if (len > 0 && !m_write(m_context, reinterpret_cast<const uint8_t *>(pbase()), len)) {
+            return -1;
+        }
+        setp(m_buffer, m_buffer + stream_buffer_size);
+        return 0;
+    }
+
+  private:
+    void *m_context;
+    write_callback m_write;
+    char m_buffer[stream_buffer_size];
+};
+
+// Stream buffer which is refilled from a Rust reader.
+class reader_buffer : public std::streambuf {
+  public:
+    reader_buffer(void *context, read_callback read) : m_context(context), m_read(read) {
+        setg(m_buffer, m_buffer, m_buffer);
+    }
+
+  protected:
+    int_type underflow() override {
+        const size_t len = m_read(m_context, reinterpret_cast<uint8_t *>(m_buffer), stream_buffer_size);
+        if (len == 0) {
+            return traits_type::eof();
+        }
+        setg(m_buffer, m_buffer, m_buffer + len);
+        return traits_type::to_int_type(*gptr());
+    }
+
+  private:
+    void *m_context;
+    read_callback m_read;
+    char m_buffer[stream_buffer_size];
+};
+
+// Stream buffer which reads a byte buffer in place.
+class bytes_buffer : public std::streambuf {
+  public:
+    bytes_buffer(const uint8_t *data, size_t len) {
+        char *begin = const_cast<char *>(reinterpret_cast<const char *>(data));
+        setg(begin, begin, begin + len);
+    }
+};
+}
+
+// File functions of the sdsl-c template, which may throw.
+extern "C" {
+bool SDSL_C_FUNCTION(store_to_file)(void *ptr, const char *file_path);
+bool SDSL_C_FUNCTION(store_int_vector_to_file)(void *ptr, const char *file_path, bool write_fixed_as_variable);
+bool SDSL_C_FUNCTION(load_from_file)(void *ptr, const char *file_path);
+}
+
+extern "C" bool SDSL_C_FUNCTION(try_store_to_file)(void *ptr, const char *file_path) {
+    return guard(false, [&] { return SDSL_C_FUNCTION(store_to_file)(ptr, file_path); });
+}
+
+extern "C" bool SDSL_C_FUNCTION(try_store_int_vector_to_file)(void *ptr, const char *file_path,
+                                                              bool write_fixed_as_variable) {
+    return guard(false,
+                 [&] { return SDSL_C_FUNCTION(store_int_vector_to_file)(ptr, file_path, write_fixed_as_variable); });
+}
+
+extern "C" bool SDSL_C_FUNCTION(try_load_from_file)(void *ptr, const char *file_path) {
+    return guard(false, [&] { return SDSL_C_FUNCTION(load_from_file)(ptr, file_path); });
+}
+
+extern "C" uint64_t SDSL_C_FUNCTION(serialize_to_writer)(void *ptr, void *context, write_callback write) {
+    return guard<uint64_t>(0, [&] {
+        writer_buffer buffer(context, write);
+        std::ostream out(&buffer);
+        const uint64_t written = structure(ptr).serialize(out);
+        out.flush();
+        return written;
+    });
+}
+
+extern "C" bool SDSL_C_FUNCTION(load_from_reader)(void *ptr, void *context, read_callback read) {
+    return guard(false, [&] {
+        reader_buffer buffer(context, read);
+        std::istream in(&buffer);
+        structure(ptr).load(in);
+        return static_cast<bool>(in);
+    });
+}
+
+extern "C" uint8_t *SDSL_C_FUNCTION(serialize_to_bytes)(void *ptr, size_t *len) {
+    *len = 0;
+    return guard<uint8_t *>(nullptr, [&] {
+        std::ostringstream out;
+        structure(ptr).serialize(out);
+        const std::string bytes = out.str();
+        uint8_t *data = new uint8_t[bytes.size()];
+        std::memcpy(data, bytes.data(), bytes.size());
+        *len = bytes.size();
+        return data;
+    });
+}
+
+extern "C" void SDSL_C_FUNCTION(free_bytes)(uint8_t *data) {
+    delete[] data;
+}
+
+extern "C" bool SDSL_C_FUNCTION(load_from_bytes)(void *ptr, const uint8_t *data, size_t len) {
+    return guard(false, [&] {
+        bytes_buffer buffer(data, len);
+        std::istream in(&buffer);
+        structure(ptr).load(in);
+        return static_cast<bool>(in);
+    });
+}
+
+extern "C" uint64_t SDSL_C_FUNCTION(size_in_bytes)(void *ptr) {
+    return sdsl::size_in_bytes(structure(ptr));
+}
+
+extern "C" char *SDSL_C_FUNCTION(write_structure_json)(void *ptr) {
+    return guard<char *>(nullptr, [&] {
+        std::ostringstream out;
+        sdsl::write_structure<sdsl::JSON_FORMAT>(structure(ptr), out);
+        return copy_string(out.str());
+    });
+}
+
+extern "C" char *SDSL_C_FUNCTION(write_structure_html)(void *ptr) {
+    return guard<char *>(nullptr, [&] {
+        std::ostringstream out;
+        sdsl::write_structure<sdsl::HTML_FORMAT>(structure(ptr), out);
+        return copy_string(out.str());
+    });
+}
+
+extern "C" void SDSL_C_FUNCTION(free_structure_string)(char *string) {
+    delete[] string;
+}
@@ -0,0 +1,22 @@
+#include <sdsl/rank_support_v.hpp>
+
+#define EXTENSION_ID _id
+#define STRUCTURE sdsl::int_vector<0>
+#include "extensions/extension.hpp"
+
+extern "C" void SDSL_C_FUNCTION(rank_support_v_rank_many)(void *ptr, const size_t *indexes, size_t len,
+                                                          size_t *ranks) {
+    const auto &support = structure(ptr);
+    for (size_t i = 0; i < len; ++i) {
+        ranks[i] = support.rank(indexes[i]);
+    }
+}
+
+extern "C" void SDSL_C_FUNCTION(rank_support_v_set_vector)(void *ptr, const void *bit_vector) {
+    structure(ptr).set_vector(static_cast<const sdsl::bit_vector *>(bit_vector));
+}
+
+extern "C" void *SDSL_C_FUNCTION(rank_support_v_try_create)(const void *bit_vector) {
+    return guard<void *>(nullptr,
+                         [&] { return new structure_type(static_cast<const sdsl::bit_vector *>(bit_vector)); });
+}
@@ -0,0 +1,17 @@
+#include <sdsl/rrr_vector.hpp>
+
+#define EXTENSION_ID _id
+#define STRUCTURE sdsl::int_vector<0>
+#include "extensions/extension.hpp"
+
+extern "C" void *SDSL_C_FUNCTION(rrr_vector_try_create)(const void *bit_vector) {
+    return guard<void *>(nullptr, [&] { return new structure_type(*static_cast<const sdsl::bit_vector *>(bit_vector)); });
+}
+
+extern "C" void *SDSL_C_FUNCTION(rrr_vector_try_default)() {
+    return guard<void *>(nullptr, [&] { return new structure_type(); });
+}
+
+extern "C" void *SDSL_C_FUNCTION(rrr_vector_try_copy)(void *ptr) {
+    return guard<void *>(nullptr, [&] { return new structure_type(structure(ptr)); });
+}
@@ -0,0 +1,35 @@
+#include <sdsl/select_support_mcl.hpp>
+
+#define EXTENSION_ID _id
+#define STRUCTURE sdsl::int_vector<0>
+#include "extensions/extension.hpp"
+
+extern "C" void SDSL_C_FUNCTION(select_support_mcl_select_many)(void *ptr, const size_t *indexes, size_t len,
+                                                                size_t *positions) {
+    const auto &support = structure(ptr);
+    for (size_t i = 0; i < len; ++i) {
+        positions[i] = support.select(indexes[i]);
+    }
+}
+
+namespace {
+// Bit pattern trait of a select support, which counts the pattern instances in a bit vector.
+template <typename t_select_support> struct pattern_trait;
+
+template <uint8_t t_b, uint8_t t_pat_len> struct pattern_trait<sdsl::select_support_mcl<t_b, t_pat_len>> {
+    typedef sdsl::select_support_trait<t_b, t_pat_len> type;
+};
+}
+
+extern "C" size_t SDSL_C_FUNCTION(select_support_mcl_count)(const void *bit_vector) {
+    return pattern_trait<structure_type>::type::arg_cnt(*static_cast<const sdsl::bit_vector *>(bit_vector));
+}
+
+extern "C" void SDSL_C_FUNCTION(select_support_mcl_set_vector)(void *ptr, const void *bit_vector) {
+    structure(ptr).set_vector(static_cast<const sdsl::bit_vector *>(bit_vector));
+}
+
+extern "C" void *SDSL_C_FUNCTION(select_support_mcl_try_create)(const void *bit_vector) {
+    return guard<void *>(nullptr,
+                         [&] { return new structure_type(static_cast<const sdsl::bit_vector *>(bit_vector)); });
+}
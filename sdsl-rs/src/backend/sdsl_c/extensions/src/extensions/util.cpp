@@ -0,0 +1,59 @@
+#include <random>
+#include <utility>
+
+#include <sdsl/int_vector.hpp>
+#include <sdsl/util.hpp>
+
+#define EXTENSION_ID _id
+#define STRUCTURE sdsl::int_vector<0>
+#include "extensions/extension.hpp"
+
+extern "C" void SDSL_C_FUNCTION(set_random_bits_with_seed)(void *ptr, uint64_t seed) {
+    // Same as sdsl::util::set_random_bits, except that every seed, including 0, is used as given.
+    auto &vector = structure(ptr);
+    std::mt19937_64 rng(seed);
+    uint64_t *data = vector.data();
+    for (size_t i = 0; i < (vector.capacity() >> 6); ++i) {
+        data[i] = rng();
+    }
+}
+
+extern "C" uint64_t SDSL_C_FUNCTION(cnt_one_bits)(void *ptr) {
+    return sdsl::util::cnt_one_bits(structure(ptr));
+}
+
+extern "C" uint64_t SDSL_C_FUNCTION(cnt_onezero_bits)(void *ptr) {
+    return sdsl::util::cnt_onezero_bits(structure(ptr));
+}
+
+extern "C" uint64_t SDSL_C_FUNCTION(cnt_zeroone_bits)(void *ptr) {
+    return sdsl::util::cnt_zeroone_bits(structure(ptr));
+}
+
+extern "C" void SDSL_C_FUNCTION(clear)(void *ptr) {
+    sdsl::util::clear(structure(ptr));
+}
+
+extern "C" void SDSL_C_FUNCTION(swap)(void *ptr, void *other) {
+    std::swap(structure(ptr), structure(other));
+}
+
+extern "C" char *SDSL_C_FUNCTION(to_string)(void *ptr) {
+    return copy_string(sdsl::util::to_string(structure(ptr)));
+}
+
+extern "C" char *SDSL_C_FUNCTION(to_latex_string)(void *ptr) {
+    return copy_string(sdsl::util::to_latex_string(structure(ptr)));
+}
+
+extern "C" char *SDSL_C_FUNCTION(class_name)(void *ptr) {
+    return copy_string(sdsl::util::class_name(structure(ptr)));
+}
+
+extern "C" void SDSL_C_FUNCTION(free_string)(char *string) {
+    delete[] string;
+}
+
+extern "C" void SDSL_C_FUNCTION(try_expand_width)(void *ptr, uint8_t new_width) {
+    guard([&] { sdsl::util::expand_width(structure(ptr), new_width); });
+}
@@ -0,0 +1,57 @@
+#include <sdsl/wavelet_trees.hpp>
+
+#define EXTENSION_ID _id
+#define STRUCTURE sdsl::int_vector<0>
+#define WAVELET_TREE wt_int
+#include "extensions/extension.hpp"
+
+// Name of the C function `name` prefixed with the wavelet tree name, for example `wt_int_get_many`.
+#define WAVELET_TREE_FUNCTION(name) SDSL_C_FUNCTION(SDSL_C_CONCAT(WAVELET_TREE, name))
+
+// Constructors of the sdsl-c template, which may throw.
+extern "C" {
+void *WAVELET_TREE_FUNCTION(_from_file)(const char *file_path);
+void *WAVELET_TREE_FUNCTION(_from_string)(const char *string);
+void *WAVELET_TREE_FUNCTION(_from_int_vector)(void *int_vector);
+void *WAVELET_TREE_FUNCTION(_from_bit_vector)(void *bit_vector);
+}
+
+extern "C" void WAVELET_TREE_FUNCTION(_get_many)(void *ptr, const size_t *indexes, size_t len,
+                                                 structure_type::value_type *values) {
+    const auto &tree = structure(ptr);
+    for (size_t i = 0; i < len; ++i) {
+        values[i] = tree[indexes[i]];
+    }
+}
+
+extern "C" void WAVELET_TREE_FUNCTION(_get_range)(void *ptr, size_t start, size_t len,
+                                                  structure_type::value_type *values) {
+    const auto &tree = structure(ptr);
+    for (size_t i = 0; i < len; ++i) {
+        values[i] = tree[start + i];
+    }
+}
+
+extern "C" void *WAVELET_TREE_FUNCTION(_try_create)() {
+    return guard<void *>(nullptr, [&] { return new structure_type(); });
+}
+
+extern "C" void *WAVELET_TREE_FUNCTION(_try_from_file)(const char *file_path) {
+    return guard<void *>(nullptr, [&] { return WAVELET_TREE_FUNCTION(_from_file)(file_path); });
+}
+
+extern "C" void *WAVELET_TREE_FUNCTION(_try_from_string)(const char *string) {
+    return guard<void *>(nullptr, [&] { return WAVELET_TREE_FUNCTION(_from_string)(string); });
+}
+
+extern "C" void *WAVELET_TREE_FUNCTION(_try_from_int_vector)(void *int_vector) {
+    return guard<void *>(nullptr, [&] { return WAVELET_TREE_FUNCTION(_from_int_vector)(int_vector); });
+}
+
+extern "C" void *WAVELET_TREE_FUNCTION(_try_from_bit_vector)(void *bit_vector) {
+    return guard<void *>(nullptr, [&] { return WAVELET_TREE_FUNCTION(_from_bit_vector)(bit_vector); });
+}
+
+extern "C" void *WAVELET_TREE_FUNCTION(_try_copy)(void *ptr) {
+    return guard<void *>(nullptr, [&] { return new structure_type(structure(ptr)); });
+}
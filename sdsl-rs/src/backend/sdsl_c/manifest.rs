@@ -0,0 +1,177 @@
+use anyhow::{format_err, Result};
+
+use super::specification;
+
+/// Name of the generated source file which embeds the manifest in the library.
+pub static SOURCE_FILE_NAME: &str = "instantiations.cpp";
+
+/// C function which returns the manifest of compiled structure types as JSON.
+static FUNCTION_NAME: &str = "sdsl_c_instantiations";
+
+/// Delimiters of the raw string literal which holds the manifest in the generated source.
+static MANIFEST_START: &str = "R\"sdsl_manifest(";
+static MANIFEST_END: &str = ")sdsl_manifest\"";
+
+/// A structure type compiled into the SDSL C interface library.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(crate = "serde")]
+pub struct Instantiation {
+    id: String,
+    c_type: String,
+}
+
+impl Instantiation {
+    /// Identifier which suffixes the C functions of the structure type.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The SDSL C++ type, such as `sdsl::rrr_vector<63, sdsl::int_vector<>, 32>`.
+    pub fn c_type(&self) -> &str {
+        &self.c_type
+    }
+}
+
+/// Returns the C++ source of a function which returns the manifest of compiled structure types.
+///
+/// The manifest is embedded in the library, so that it is found wherever the library is: loaded from any directory,
+/// found on the system library search path, or linked statically.
+pub fn source(specifications: &[specification::Specification]) -> Result<String> {
+    let mut instantiations = Vec::<Instantiation>::new();
+    for specification in specifications {
+        let instantiation = Instantiation {
+            id: specification::get_id(&specification.c_code)?,
+            c_type: specification.c_code.clone(),
+        };
+        if !instantiations.contains(&instantiation) {
+            instantiations.push(instantiation);
+        }
+    }
+    let manifest = serde_json::to_string_pretty(&instantiations)?;
+    Ok(format!(
+        "// Generated by sdsl-rs: the structure types compiled into this library.\n\
+         extern \"C\" const char *{function_name}() {{\n    \
+         return {start}{manifest}{end};\n}}\n",
+        function_name = FUNCTION_NAME,
+        start = MANIFEST_START,
+        manifest = manifest,
+        end = MANIFEST_END
+    ))
+}
+
+/// Returns the structure types listed in a source generated by `source`.
+pub fn parse_source(source: &str) -> Result<Vec<Instantiation>> {
+    let manifest = source
+        .find(MANIFEST_START)
+        .map(|start| &source[start + MANIFEST_START.len()..])
+        .and_then(|manifest| manifest.find(MANIFEST_END).map(|end| &manifest[..end]))
+        .ok_or_else(|| format_err!("No manifest found in source."))?;
+    Ok(serde_json::from_str(manifest)?)
+}
+
+fn read() -> Result<Manifest> {
+    let lib = super::lib().map_err(|e| format_err!("{}", e))?;
+    let function: extern "C" fn() -> *const std::os::raw::c_char = lib
+        .find_function(FUNCTION_NAME)
+        .map_err(|e| format_err!("{}", e))?;
+    let manifest = unsafe { std::ffi::CStr::from_ptr(function()) }.to_str()?;
+    let instantiations: Vec<Instantiation> = serde_json::from_str(manifest)?;
+    let ids = instantiations.iter().map(|i| i.id.clone()).collect();
+    Ok(Manifest {
+        instantiations,
+        ids,
+    })
+}
+
+struct Manifest {
+    instantiations: Vec<Instantiation>,
+    ids: std::collections::HashSet<String>,
+}
+
+// Read once, since the manifest is checked whenever a structure is constructed.
+lazy_static::lazy_static! {
+    static ref MANIFEST: std::result::Result<Manifest, crate::Error> =
+        read().map_err(|e| crate::Error::LibraryNotLoaded(e.to_string()));
+}
+
+/// Returns the structure types compiled into the SDSL C interface library.
+///
+/// # Example
+/// ```ignore
+/// for instantiation in sdsl::available_instantiations()? {
+///     println!("{}", instantiation.c_type());
+/// }
+/// ```
+pub fn available_instantiations() -> crate::Result<Vec<Instantiation>> {
+    match MANIFEST.as_ref() {
+        Ok(manifest) => Ok(manifest.instantiations.clone()),
+        Err(error) => Err(error.clone()),
+    }
+}
+
+/// Returns an error naming the Rust type if the structure type with the given id was not compiled.
+///
+/// Libraries built without a manifest are not checked.
+pub fn check_instantiation(id: &str, type_name: &str) -> crate::Result<()> {
+    let manifest = match MANIFEST.as_ref() {
+        Ok(manifest) => manifest,
+        Err(_) => return Ok(()),
+    };
+    if manifest.ids.contains(id) {
+        return Ok(());
+    }
+    Err(crate::Error::NotInstantiated {
+        type_name: public_type_name(type_name),
+    })
+}
+
+/// Rewrite the paths of a type name, as given by `std::any::type_name`, to the paths exported by this crate.
+fn public_type_name(type_name: &str) -> String {
+    let metas = match crate::meta::get_metas() {
+        Ok(metas) => metas,
+        Err(_) => return type_name.to_string(),
+    };
+    let regex = regex::Regex::new(r"sdsl::interface::(\w+::)*(?P<name>\w+)")
+        .expect("Failed to compile type path regex.");
+    regex
+        .replace_all(type_name, |captures: &regex::Captures| {
+            let name = &captures["name"];
+            metas
+                .iter()
+                .map(|meta| meta.path())
+                .find(|path| path.rsplit("::").next() == Some(name))
+                .unwrap_or_else(|| captures[0].to_string())
+        })
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_embeds_manifest() -> Result<()> {
+        let source = source(&[])?;
+        let result = source.lines().nth(1);
+        let expected = Some("extern \"C\" const char *sdsl_c_instantiations() {");
+        assert_eq!(result, expected);
+        assert!(source.contains("R\"sdsl_manifest([])sdsl_manifest\""));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_source() -> Result<()> {
+        let specifications = crate::backend::analyse::analyse_specs(&[crate::spec!(
+            sdsl::int_vectors::IntVector<0>
+        )])?;
+        let source = source(&specifications)?;
+
+        let result: Vec<_> = parse_source(&source)?
+            .iter()
+            .map(|instantiation| instantiation.c_type().to_string())
+            .collect();
+        let expected = vec!["sdsl::int_vector<0>".to_string()];
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
@@ -1,32 +1,255 @@
 use anyhow::{format_err, Result};
 use sharedlib::Symbol;
 
+pub mod cache;
+pub mod manifest;
+pub mod registry;
+pub mod sdsl_lite;
 pub mod specification;
 pub mod template;
 
 // Ensure shared lib is loaded once. Ensure lib does not fail to load due to IO race conditions.
 lazy_static::lazy_static! {
-    pub static ref LIB: std::sync::Arc<sharedlib::Lib> = get_lib().unwrap();
+    static ref LIB: std::result::Result<std::sync::Arc<Library>, crate::Error> =
+        get_lib().map_err(|e| crate::Error::LibraryNotLoaded(e.to_string()));
 }
 
-pub fn get_lib() -> Result<std::sync::Arc<sharedlib::Lib>> {
+/// Returns the shared lib, loading it on first use.
+pub fn lib() -> std::result::Result<std::sync::Arc<Library>, crate::Error> {
+    LIB.clone()
+}
+
+/// Access to the thread-local slot in which guarded C functions store caught C++ exceptions.
+///
+/// Unwinding through `extern "C"` functions is undefined behaviour. The C functions which may throw, i.e. those which
+/// allocate in proportion to their input or which read and write files and streams, are therefore called through
+/// guarded wrappers of the extension sources. The wrappers catch the exception and return a null or default value.
+struct LastErrorInterface {
+    take_last_error: extern "C" fn() -> *mut std::os::raw::c_char,
+    free_last_error: extern "C" fn(*mut std::os::raw::c_char),
+
+    _lib: std::sync::Arc<Library>,
+}
+
+impl LastErrorInterface {
+    fn new() -> std::result::Result<Self, crate::Error> {
+        let lib = lib()?;
+        Ok(Self {
+            take_last_error: lib.find_function("take_last_error")?,
+            free_last_error: lib.find_function("free_last_error")?,
+
+            _lib: lib.clone(),
+        })
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref LAST_ERROR: std::result::Result<LastErrorInterface, crate::Error> =
+        LastErrorInterface::new();
+}
+
+/// Returns an error if a guarded C function called on this thread caught a C++ exception since the last check.
+///
+/// The exception message is removed from the slot. Call after every guarded C function.
+pub fn check_last_error() -> std::result::Result<(), crate::Error> {
+    let interface = LAST_ERROR.as_ref().map_err(|error| error.clone())?;
+    let message = (interface.take_last_error)();
+    if message.is_null() {
+        return Ok(());
+    }
+    let error = unsafe { std::ffi::CStr::from_ptr(message) }
+        .to_string_lossy()
+        .into_owned();
+    (interface.free_last_error)(message);
+    Err(crate::Error::CppException(error))
+}
+
+/// The SDSL C interface library.
+///
+/// The library is either a shared lib loaded at runtime, or statically linked into the executable when the `static`
+/// feature is enabled.
+pub struct Library {
+    // None if statically linked.
+    shared: Option<sharedlib::Lib>,
+}
+
+impl Library {
+    fn find_function<T: Copy>(&self, name: &str) -> std::result::Result<T, crate::Error> {
+        let symbol_not_found = || crate::Error::SymbolNotFound {
+            name: name.to_string(),
+        };
+        match &self.shared {
+            Some(lib) => unsafe {
+                let symbol: sharedlib::Func<T> =
+                    lib.find_func(name).map_err(|_| symbol_not_found())?;
+                Ok(symbol.get())
+            },
+            None => {
+                let c_name = std::ffi::CString::new(name)?;
+                let function = find_static_function(&c_name);
+                if function.is_null() {
+                    return Err(symbol_not_found());
+                }
+                // Function pointers have the size of data pointers on all supported platforms.
+                assert_eq!(
+                    std::mem::size_of::<T>(),
+                    std::mem::size_of::<*const libc::c_void>()
+                );
+                Ok(unsafe { std::mem::transmute_copy::<*const libc::c_void, T>(&function) })
+            }
+        }
+    }
+}
+
+#[cfg(feature = "static")]
+extern "C" {
+    // Generated function table of the static library, see `registry`.
+    fn sdsl_c_find_function(name: *const std::os::raw::c_char) -> *const libc::c_void;
+}
+
+/// Returns the address of a function of the statically linked library, or null.
+#[cfg(feature = "static")]
+fn find_static_function(name: &std::ffi::CStr) -> *const libc::c_void {
+    unsafe { sdsl_c_find_function(name.as_ptr()) }
+}
+
+#[cfg(not(feature = "static"))]
+fn find_static_function(_name: &std::ffi::CStr) -> *const libc::c_void {
+    std::ptr::null()
+}
+
+/// Returns the file name of the SDSL C shared library on the given target OS, as named by CMake.
+/// # Arguments
+/// * `target_os` - OS name as given by `std::env::consts::OS` or `CARGO_CFG_TARGET_OS`.
+pub fn lib_file_name(target_os: &str) -> &'static str {
+    match target_os {
+        "macos" | "ios" => "libsdsl_c.dylib",
+        "windows" => "sdsl_c.dll",
+        _ => "libsdsl_c.so",
+    }
+}
+
+/// Environment variable with the path of the SDSL C shared library, or of its directory.
+pub static ENV_LIB_PATH: &str = "SDSL_C_LIB_PATH";
+
+lazy_static::lazy_static! {
+    // Path given by init_with_library, and whether loading the shared lib has started.
+    static ref LIB_PATH: std::sync::Mutex<(Option<std::path::PathBuf>, bool)> =
+        std::sync::Mutex::new((None, false));
+}
+
+/// Use the SDSL C shared library at `path`.
+///
+/// Must be called before any structure is constructed. The library is otherwise searched for in order:
+/// the `SDSL_C_LIB_PATH` env variable, the executable's directory, the system library search path, which includes
+/// the executable's rpath, and finally the build directory given by `OUT_DIR`.
+/// # Arguments
+/// * `path` - Path of the shared library, such as `libsdsl_c.so`.
+pub fn init_with_library(path: &std::path::Path) -> crate::Result<()> {
+    if !path.is_file() {
+        return Err(crate::Error::io(
+            std::io::ErrorKind::NotFound,
+            format!("SDSL C interface library not found: {}", path.display()),
+        ));
+    }
+    let mut lib_path = LIB_PATH.lock().expect("Failed to lock library path.");
+    if lib_path.1 {
+        return Err(crate::Error::InvalidArgument(
+            "The SDSL C interface library is already loaded.".to_string(),
+        ));
+    }
+    lib_path.0 = Some(path.to_path_buf());
+    Ok(())
+}
+
+pub fn get_lib() -> Result<std::sync::Arc<Library>> {
+    if cfg!(feature = "static") {
+        return Ok(std::sync::Arc::new(Library { shared: None }));
+    }
+
+    let lib_file_name = lib_file_name(std::env::consts::OS);
+    let mut searched = Vec::<String>::new();
+    if let Some(lib) = load_first(&lib_path_candidates(lib_file_name)?, &mut searched)? {
+        return Ok(lib);
+    }
+
+    // The system search path includes LD_LIBRARY_PATH and the executable's rpath, such as $ORIGIN.
+    if let Ok(lib) = unsafe { sharedlib::Lib::new(lib_file_name) } {
+        return Ok(std::sync::Arc::new(Library { shared: Some(lib) }));
+    }
+    searched.push(format!("{} (system library search path)", lib_file_name));
+
+    // Cargo sets OUT_DIR when it runs tests and examples of the package which built the library.
+    if let Ok(directory) = lib_directory() {
+        if let Some(lib) = load_first(&[directory.join(lib_file_name)], &mut searched)? {
+            return Ok(lib);
+        }
+    }
+
+    Err(format_err!(
+        "Failed to find {}. Searched:\n{}",
+        lib_file_name,
+        searched.join("\n")
+    ))
+}
+
+/// Load the first existing library of `lib_paths`, and record the paths which do not exist in `searched`.
+fn load_first(
+    lib_paths: &[std::path::PathBuf],
+    searched: &mut Vec<String>,
+) -> Result<Option<std::sync::Arc<Library>>> {
+    for lib_path in lib_paths {
+        if !lib_path.is_file() {
+            searched.push(lib_path.display().to_string());
+            continue;
+        }
+        log::debug!("Loading SDSL C library: {}", lib_path.display());
+        let lib = unsafe { sharedlib::Lib::new(lib_path) }
+            .map_err(|e| format_err!("Failed to load {}: {}", lib_path.display(), e))?;
+        return Ok(Some(std::sync::Arc::new(Library { shared: Some(lib) })));
+    }
+    Ok(None)
+}
+
+/// Returns the library paths to try before the system library search path, in order of precedence.
+fn lib_path_candidates(lib_file_name: &str) -> Result<Vec<std::path::PathBuf>> {
+    let mut candidates = Vec::<_>::new();
+
+    let mut lib_path = LIB_PATH.lock().expect("Failed to lock library path.");
+    lib_path.1 = true;
+    if let Some(path) = &lib_path.0 {
+        candidates.push(path.clone());
+    }
+
+    if let Some(path) = std::env::var_os(ENV_LIB_PATH) {
+        let path = std::path::PathBuf::from(path);
+        if path.is_dir() {
+            candidates.push(path.join(lib_file_name));
+        } else {
+            candidates.push(path);
+        }
+    }
+    if let Some(directory) = std::env::current_exe()?.parent() {
+        candidates.push(directory.join(lib_file_name));
+    }
+    Ok(candidates)
+}
+
+/// Returns the directory of the shared lib in the build directory.
+fn lib_directory() -> Result<std::path::PathBuf> {
     let out_directory = std::env::var("OUT_DIR").map_err(|e| format_err!("{}", e))?;
     let out_directory = std::path::PathBuf::from(&out_directory);
-    let lib_path = out_directory.join("lib").join("libsdsl_c.so");
-    unsafe {
-        let lib = sharedlib::Lib::new(lib_path).map_err(|e| format_err!("{}", e))?;
-        Ok(std::sync::Arc::new(lib))
-    }
+    Ok(out_directory.join("lib"))
 }
 
 pub struct FunctionBuilder {
     base_name: Option<String>,
     id: String,
-    lib: std::sync::Arc<sharedlib::Lib>,
+    lib: std::sync::Arc<Library>,
 }
 
 impl FunctionBuilder {
-    pub fn new<'a>(base_name: Option<&str>, id: &str, lib: std::sync::Arc<sharedlib::Lib>) -> Self {
+    pub fn new<'a>(base_name: Option<&str>, id: &str, lib: std::sync::Arc<Library>) -> Self {
         Self {
             base_name: base_name.and_then(|f| Some(f.to_string())),
             id: id.to_string(),
@@ -34,16 +257,8 @@ impl FunctionBuilder {
         }
     }
 
-    pub fn get<'a, T: Copy>(&self, name: &str) -> Result<T> {
-        let full_name = self.full_name(&name);
-        unsafe {
-            let symbol: sharedlib::Func<T> = self
-                .lib
-                .find_func(full_name)
-                .map_err(|e| format_err!("{}", e))?;
-            let function = symbol.get();
-            Ok(function)
-        }
+    pub fn get<'a, T: Copy>(&self, name: &str) -> std::result::Result<T, crate::Error> {
+        self.lib.find_function(&self.full_name(name))
     }
 
     fn full_name(&self, name: &str) -> String {
@@ -58,3 +273,18 @@ impl FunctionBuilder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lib_file_name() {
+        let result: Vec<_> = ["linux", "macos", "windows"]
+            .iter()
+            .map(|target_os| lib_file_name(target_os))
+            .collect();
+        let expected = vec!["libsdsl_c.so", "libsdsl_c.dylib", "sdsl_c.dll"];
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,133 @@
+use anyhow::{format_err, Result};
+
+/// Name of the generated source file of the function table, compiled into the static library only.
+pub static SOURCE_FILE_NAME: &str = "registry.cpp";
+
+/// C function which returns the address of a library function by name, or null.
+///
+/// Executables reference this single function, so the linker binds it at link time. It references every other
+/// function of the library, so the linker keeps them all.
+static FUNCTION_NAME: &str = "sdsl_c_find_function";
+
+/// Returns the C++ source of the function table for the C functions defined in the static library at `lib_path`.
+///
+/// The functions are listed with `nm`, or the tool given by the `NM` env variable.
+pub fn source(lib_path: &std::path::Path) -> Result<String> {
+    let nm = std::env::var("NM").unwrap_or_else(|_| "nm".to_string());
+    log::debug!(
+        "Executing command: {} --defined-only -g {}",
+        nm,
+        lib_path.display()
+    );
+    let output = std::process::Command::new(&nm)
+        .args(vec!["--defined-only", "-g"])
+        .arg(lib_path)
+        .stderr(std::process::Stdio::piped())
+        .output()
+        .map_err(|e| format_err!("Failed to execute {}: {}", nm, e))?;
+    if !output.status.success() {
+        return Err(format_err!(
+            "Failed to list symbols of {}.\nStderr:\n{stderr}",
+            lib_path.display(),
+            stderr = String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    let names = function_names(&String::from_utf8_lossy(&output.stdout));
+    Ok(table_source(&names))
+}
+
+/// Returns the C functions in the output of `nm`: defined text symbols which are not mangled C++ names.
+fn function_names(nm_output: &str) -> Vec<String> {
+    let mut names = std::collections::BTreeSet::new();
+    for line in nm_output.lines() {
+        let fields: Vec<_> = line.split_whitespace().collect();
+        if let [_address, "T", name] = fields.as_slice() {
+            if !name.starts_with("_Z") && *name != FUNCTION_NAME {
+                names.insert(name.to_string());
+            }
+        }
+    }
+    names.into_iter().collect()
+}
+
+/// Returns the C++ source of the function table.
+///
+/// The functions are declared without their signatures, which suffices to take their addresses.
+fn table_source(names: &[String]) -> String {
+    let declarations: String = names
+        .iter()
+        .map(|name| format!("void {}();\n", name))
+        .collect();
+    let entries: String = names
+        .iter()
+        .map(|name| {
+            format!(
+                "    {{\"{name}\", reinterpret_cast<void *>(&{name})}},\n",
+                name = name
+            )
+        })
+        .collect();
+    format!(
+        "// Generated by sdsl-rs: the C functions of this library by name.\n\
+         #include <cstring>\n\
+         \n\
+         extern \"C\" {{\n\
+         {declarations}}}\n\
+         \n\
+         namespace {{\n\
+         struct Entry {{\n    const char *name;\n    void *function;\n}};\n\
+         \n\
+         const Entry ENTRIES[] = {{\n\
+         {entries}    {{nullptr, nullptr}},\n\
+         }};\n\
+         }}\n\
+         \n\
+         extern \"C\" void *{function_name}(const char *name) {{\n    \
+             for (const Entry *entry = ENTRIES; entry->name != nullptr; ++entry) {{\n        \
+                 if (std::strcmp(entry->name, name) == 0) {{\n            \
+                     return entry->function;\n        \
+                 }}\n    \
+             }}\n    \
+             return nullptr;\n\
+         }}\n",
+        declarations = declarations,
+        entries = entries,
+        function_name = FUNCTION_NAME
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_function_names() {
+        let nm_output = "
+int_vector_abc.cpp.o:
+0000000000000000 T int_vector_create_abc
+0000000000000010 T _ZN4sdsl10int_vectorILh0EE6resizeEm
+0000000000000020 W _ZN4sdsl10int_vectorILh0EED2Ev
+0000000000000000 B sdsl_c_error_slot
+
+registry.cpp.o:
+0000000000000000 T sdsl_c_find_function
+0000000000000000 T take_last_error
+";
+        let result = function_names(nm_output);
+        let expected = vec![
+            "int_vector_create_abc".to_string(),
+            "take_last_error".to_string(),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_table_source_lists_functions() {
+        let source = table_source(&["int_vector_create_abc".to_string()]);
+        assert!(source.contains("void int_vector_create_abc();\n"));
+        assert!(source.contains(
+            "{\"int_vector_create_abc\", reinterpret_cast<void *>(&int_vector_create_abc)},"
+        ));
+        assert!(source.contains("extern \"C\" void *sdsl_c_find_function(const char *name) {"));
+    }
+}
@@ -0,0 +1,229 @@
+use anyhow::Result;
+
+/// Environment variable with the installation prefix of SDSL-lite.
+pub static ENV_SDSL_ROOT: &str = "SDSL_ROOT";
+
+/// An SDSL-lite installation found on the system.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SdslLite {
+    pub include_directories: Vec<std::path::PathBuf>,
+    pub lib_directories: Vec<std::path::PathBuf>,
+}
+
+/// Find an installed SDSL-lite.
+///
+/// Looks in order at the `SDSL_ROOT` env variable, pkg-config and the CMake search paths.
+/// Returns None if SDSL-lite is not installed, in which case the vendored copy is compiled.
+pub fn find(out_directory: &std::path::Path) -> Result<Option<SdslLite>> {
+    println!("cargo:rerun-if-env-changed={}", ENV_SDSL_ROOT);
+
+    if let Some(root) = std::env::var_os(ENV_SDSL_ROOT) {
+        let root = std::path::PathBuf::from(root);
+        if !has_headers(&root.join("include")) {
+            return Err(anyhow::format_err!(
+                "{} is set but SDSL-lite headers were not found in: {}",
+                ENV_SDSL_ROOT,
+                root.join("include").display()
+            ));
+        }
+        log::info!("Using SDSL-lite from {}: {}", ENV_SDSL_ROOT, root.display());
+        return Ok(Some(SdslLite {
+            include_directories: vec![root.join("include")],
+            lib_directories: vec![root.join("lib")],
+        }));
+    }
+
+    if let Some(flags) = command_output("pkg-config", &["--cflags", "--libs", "sdsl-lite"]) {
+        let sdsl_lite = from_flags(&flags);
+        log::info!("Using SDSL-lite from pkg-config: {:?}", sdsl_lite);
+        return Ok(Some(sdsl_lite));
+    }
+
+    if let Some(sdsl_lite) = probe_cmake(out_directory)? {
+        log::info!("Using SDSL-lite from CMake: {:?}", sdsl_lite);
+        return Ok(Some(sdsl_lite));
+    }
+
+    log::info!("SDSL-lite installation not found. Using vendored copy.");
+    Ok(None)
+}
+
+fn has_headers(include_directory: &std::path::Path) -> bool {
+    include_directory
+        .join("sdsl")
+        .join("int_vector.hpp")
+        .exists()
+}
+
+/// Returns the standard output of a successful command.
+fn command_output<S: AsRef<std::ffi::OsStr>>(program: &str, args: &[S]) -> Option<String> {
+    let output = std::process::Command::new(program)
+        .args(args)
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Find SDSL-lite with `find_path` and `find_library` in a CMake probe project.
+///
+/// The probe is configured like the interface project, so it searches the same paths, including `CMAKE_PREFIX_PATH`,
+/// with the compiler given by the `CXX` env variable.
+fn probe_cmake(out_directory: &std::path::Path) -> Result<Option<SdslLite>> {
+    let probe_directory = out_directory.join("sdsl-lite-probe");
+    let build_directory = probe_directory.join("build");
+    std::fs::create_dir_all(&build_directory)?;
+    std::fs::write(probe_directory.join("CMakeLists.txt"), PROBE_CMAKELISTS)?;
+
+    log::debug!("Executing command: cmake {}", probe_directory.display());
+    let status = std::process::Command::new("cmake")
+        .arg(&probe_directory)
+        .current_dir(&build_directory)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status();
+    if !matches!(status, Ok(status) if status.success()) {
+        return Ok(None);
+    }
+    let result = std::fs::read_to_string(build_directory.join(PROBE_RESULT_FILE_NAME))?;
+    Ok(from_probe_result(&result))
+}
+
+/// File written by the probe project with the include directory and the library directory, one per line.
+static PROBE_RESULT_FILE_NAME: &str = "sdsl-lite.txt";
+
+static PROBE_CMAKELISTS: &str = r#"cmake_minimum_required(VERSION 3.5)
+project(sdsl_lite_probe CXX)
+
+find_path(SDSL_INCLUDE_DIR sdsl/int_vector.hpp)
+find_library(SDSL_LIBRARY sdsl)
+if(NOT SDSL_INCLUDE_DIR OR NOT SDSL_LIBRARY)
+    message(FATAL_ERROR "SDSL-lite not found.")
+endif()
+get_filename_component(SDSL_LIBRARY_DIR "${SDSL_LIBRARY}" DIRECTORY)
+file(WRITE "${CMAKE_BINARY_DIR}/sdsl-lite.txt" "${SDSL_INCLUDE_DIR}\n${SDSL_LIBRARY_DIR}\n")
+"#;
+
+/// Read the include and library directories written by the probe project.
+fn from_probe_result(result: &str) -> Option<SdslLite> {
+    let mut lines = result.lines();
+    let include_directory = lines.next().filter(|line| !line.is_empty())?;
+    let lib_directory = lines.next().filter(|line| !line.is_empty())?;
+    Some(SdslLite {
+        include_directories: vec![std::path::PathBuf::from(include_directory)],
+        lib_directories: vec![std::path::PathBuf::from(lib_directory)],
+    })
+}
+
+/// Collect the include and library directories from compiler flags.
+fn from_flags(flags: &str) -> SdslLite {
+    let mut sdsl_lite = SdslLite {
+        include_directories: vec![],
+        lib_directories: vec![],
+    };
+    for flag in flags.split_whitespace() {
+        if let Some(directory) = flag.strip_prefix("-I") {
+            sdsl_lite
+                .include_directories
+                .push(std::path::PathBuf::from(directory));
+        } else if let Some(directory) = flag.strip_prefix("-L") {
+            sdsl_lite
+                .lib_directories
+                .push(std::path::PathBuf::from(directory));
+        }
+    }
+    sdsl_lite
+}
+
+/// Returns a CMakeLists.txt which compiles the interface against an installed SDSL-lite.
+pub fn cmakelists(sdsl_lite: &SdslLite) -> String {
+    let join = |directories: &[std::path::PathBuf]| {
+        directories
+            .iter()
+            .map(|d| format!("\"{}\"", d.display()))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    format!(
+        r#"cmake_minimum_required(VERSION 3.5)
+project(sdsl_c CXX)
+
+set(CMAKE_CXX_STANDARD 14)
+option(BUILD_SHARED_LIBS "Build shared library." ON)
+
+find_library(SDSL_LIBRARY sdsl HINTS {lib_directories})
+if(NOT SDSL_LIBRARY)
+    message(FATAL_ERROR "SDSL-lite library not found.")
+endif()
+find_library(DIVSUFSORT_LIBRARY divsufsort HINTS {lib_directories})
+find_library(DIVSUFSORT64_LIBRARY divsufsort64 HINTS {lib_directories})
+
+file(GLOB_RECURSE SOURCES src/*.cpp)
+add_library(sdsl_c ${{SOURCES}})
+target_include_directories(sdsl_c PRIVATE include src {include_directories})
+target_link_libraries(sdsl_c ${{SDSL_LIBRARY}})
+if(DIVSUFSORT_LIBRARY)
+    target_link_libraries(sdsl_c ${{DIVSUFSORT_LIBRARY}})
+endif()
+if(DIVSUFSORT64_LIBRARY)
+    target_link_libraries(sdsl_c ${{DIVSUFSORT64_LIBRARY}})
+endif()
+
+install(TARGETS sdsl_c LIBRARY DESTINATION lib ARCHIVE DESTINATION lib)
+"#,
+        lib_directories = join(&sdsl_lite.lib_directories),
+        include_directories = join(&sdsl_lite.include_directories),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_flags() {
+        let result = from_flags("-I/opt/sdsl/include -DNDEBUG -L/opt/sdsl/lib -lsdsl -ldivsufsort");
+        let expected = SdslLite {
+            include_directories: vec![std::path::PathBuf::from("/opt/sdsl/include")],
+            lib_directories: vec![std::path::PathBuf::from("/opt/sdsl/lib")],
+        };
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_flags_without_directories() {
+        let result = from_flags("-lsdsl");
+        let expected = SdslLite {
+            include_directories: vec![],
+            lib_directories: vec![],
+        };
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_probe_result() {
+        let result = from_probe_result("/opt/my sdsl/include\n/opt/my sdsl/lib\n");
+        let expected = Some(SdslLite {
+            include_directories: vec![std::path::PathBuf::from("/opt/my sdsl/include")],
+            lib_directories: vec![std::path::PathBuf::from("/opt/my sdsl/lib")],
+        });
+        assert_eq!(result, expected);
+        assert_eq!(from_probe_result(""), None);
+    }
+
+    #[test]
+    fn test_cmakelists() {
+        let cmakelists = cmakelists(&SdslLite {
+            include_directories: vec![std::path::PathBuf::from("/opt/sdsl/include")],
+            lib_directories: vec![std::path::PathBuf::from("/opt/sdsl/lib")],
+        });
+        assert!(cmakelists.contains("find_library(SDSL_LIBRARY sdsl HINTS \"/opt/sdsl/lib\")"));
+        assert!(cmakelists.contains(
+            "target_include_directories(sdsl_c PRIVATE include src \"/opt/sdsl/include\")"
+        ));
+        assert!(cmakelists.contains("add_library(sdsl_c ${SOURCES})"));
+    }
+}
@@ -65,36 +65,35 @@ pub fn setup(
     specifications: &Vec<Specification>,
     template_directory: &std::path::PathBuf,
     out_directory: &std::path::PathBuf,
+    sdsl_lite: &Option<super::sdsl_lite::SdslLite>,
 ) -> Result<std::path::PathBuf> {
     log::debug!("Setting up template specification directory.");
     let (interface_directory, src_directory, include_directory) =
-        setup_static_files(&out_directory, &template_directory)?;
-    let new_files = setup_source_files(
+        setup_static_files(&out_directory, &template_directory, sdsl_lite)?;
+    setup_source_files(
         &specifications,
         &template_directory,
         &src_directory,
         &include_directory,
     )?;
-    ensure_replacements(
-        &specifications,
-        &src_directory,
-        &include_directory,
-        &new_files,
-    )?;
     Ok(interface_directory)
 }
 
+/// Write the source files of all specifications.
+///
+/// Files are only written if their content changed, so that CMake recompiles only the changed objects.
 fn setup_source_files(
     specifications: &Vec<Specification>,
     template_directory: &std::path::PathBuf,
     src_directory: &std::path::PathBuf,
     include_directory: &std::path::PathBuf,
-) -> Result<std::collections::BTreeSet<std::path::PathBuf>> {
+) -> Result<()> {
     log::debug!("Setting up source files.");
 
-    let mut new_files = std::collections::BTreeSet::<_>::new();
     let mut added_files = std::collections::BTreeSet::<_>::new();
     for specification in specifications {
+        let start = std::time::Instant::now();
+        let mut changed_files_count = 0;
         for file_specification in &specification.files {
             let (template_file_path, target_file_path) = match file_specification.c_file_type {
                 meta::common::CFileType::Hpp => {
@@ -114,21 +113,54 @@ fn setup_source_files(
                 }
             };
 
-            added_files.insert(target_file_path.clone());
-            if !target_file_path.exists() {
-                match target_file_path.parent() {
-                    Some(parent) => std::fs::create_dir_all(parent)?,
-                    None => {}
-                };
-
-                std::fs::copy(&template_file_path, &target_file_path)?;
-                new_files.insert(target_file_path.clone());
+            // Ensure files are only processed once.
+            if !added_files.insert(target_file_path.clone()) {
+                continue;
+            }
+            let content = replace_lines(&template_file_path, &file_specification.replacements)?;
+            if write_if_changed(&target_file_path, &content)? {
+                changed_files_count += 1;
             }
         }
+        log::info!(
+            "Specification {c_code}: {changed} of {total} source files changed, generated in {elapsed:?}.",
+            c_code = specification.c_code,
+            changed = changed_files_count,
+            total = specification.files.len(),
+            elapsed = start.elapsed()
+        );
     }
+    let manifest_file_path = src_directory.join(super::manifest::SOURCE_FILE_NAME);
+    write_if_changed(
+        &manifest_file_path,
+        &super::manifest::source(specifications)?,
+    )?;
+    added_files.insert(manifest_file_path);
+
     cleanup_stale_files(&added_files, &include_directory)?;
     cleanup_stale_files(&added_files, &src_directory)?;
-    Ok(new_files)
+    Ok(())
+}
+
+/// Write `content` to `path` unless the file has the same content.
+///
+/// Unchanged files keep their modification time, so that CMake does not recompile them.
+/// Returns true if the file was written.
+fn write_if_changed(path: &std::path::Path, content: &str) -> Result<bool> {
+    if path.exists() && std::fs::read(path)? == content.as_bytes() {
+        return Ok(false);
+    }
+    log::debug!("Writing source file: {}", path.display());
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let file = std::fs::File::create(path)?;
+    let mut buffered_out = std::io::BufWriter::new(&file);
+    buffered_out.write_all(content.as_bytes())?;
+    buffered_out.flush()?;
+    file.sync_all()?;
+    Ok(true)
 }
 
 fn cleanup_stale_files(
@@ -147,7 +179,10 @@ fn cleanup_stale_files(
             .and_then(|f| f.to_str())
             .ok_or(format_err!("Failed to obtain file name."))?;
 
-        if !files_to_keep.contains(&path) && file_name != "common.hpp" {
+        // The function table is generated after compilation, and only for the static library.
+        let is_registry =
+            cfg!(feature = "static") && file_name == super::registry::SOURCE_FILE_NAME;
+        if !files_to_keep.contains(&path) && file_name != "common.hpp" && !is_registry {
             log::debug!("Removing stale file: {}", path.display());
             std::fs::remove_file(&path)?;
         }
@@ -155,43 +190,11 @@ fn cleanup_stale_files(
     Ok(())
 }
 
-fn ensure_replacements(
-    specifications: &Vec<Specification>,
-    src_directory: &std::path::PathBuf,
-    include_directory: &std::path::PathBuf,
-    new_files: &std::collections::BTreeSet<std::path::PathBuf>,
-) -> Result<()> {
-    log::debug!("Editing source files with specification replacements.");
-
-    let mut processed_files = std::collections::BTreeSet::<std::path::PathBuf>::new();
-    for specification in specifications {
-        for file_specification in &specification.files {
-            let source_directory = match file_specification.c_file_type {
-                meta::common::CFileType::Hpp => include_directory,
-                meta::common::CFileType::Cpp => src_directory,
-            };
-
-            let target_file_path = source_directory.join(&file_specification.target_file_name);
-
-            // Ensure files are only processed once.
-            if !new_files.contains(&target_file_path) || processed_files.contains(&target_file_path)
-            {
-                continue;
-            }
-
-            replace_lines(&target_file_path, &file_specification.replacements)?;
-            processed_files.insert(target_file_path.clone());
-        }
-    }
-    Ok(())
-}
-
+/// Returns the content of the template file with line replacements.
 fn replace_lines(
     path: &std::path::PathBuf,
     replacements: &std::collections::BTreeMap<String, String>,
-) -> Result<()> {
-    log::debug!("Actualize line replacement in file: {}", path.display());
-
+) -> Result<String> {
     let content = std::fs::read_to_string(&path)?;
     let content_split: Vec<&str> = content.split('\n').collect();
 
@@ -203,20 +206,13 @@ fn replace_lines(
         }
         new_contents.push(line);
     }
-
-    std::fs::remove_file(&path)?;
-
-    let file = std::fs::File::create(&path).expect("Failed to create file");
-    let mut buffered_out = std::io::BufWriter::new(&file);
-    buffered_out.write_all(new_contents.join("\n").as_bytes())?;
-    file.sync_all()?;
-
-    Ok(())
+    Ok(new_contents.join("\n"))
 }
 
 fn setup_static_files(
     out_directory: &std::path::PathBuf,
     template_directory: &std::path::PathBuf,
+    sdsl_lite: &Option<super::sdsl_lite::SdslLite>,
 ) -> Result<(std::path::PathBuf, std::path::PathBuf, std::path::PathBuf)> {
     log::debug!("Setting up static files.");
 
@@ -242,8 +238,9 @@ fn setup_static_files(
         std::fs::create_dir_all(&src_directory)?;
     }
 
+    // The vendored SDSL-lite is only compiled if no installation was found.
     let template_external_directory = template_directory.join("external");
-    if !interface_directory.join("external").exists() {
+    if sdsl_lite.is_none() && !interface_directory.join("external").exists() {
         fs_extra::copy_items(
             &vec![template_external_directory],
             &interface_directory,
@@ -251,19 +248,171 @@ fn setup_static_files(
         )?;
     }
 
+    let cmakelists = match sdsl_lite {
+        Some(sdsl_lite) => super::sdsl_lite::cmakelists(sdsl_lite),
+        None => std::fs::read_to_string(
+            template_directory
+                .join("miscellaneous")
+                .join("template_CMakeLists.txt"),
+        )?,
+    };
+    // Rewritten only on change, so that CMake does not reconfigure needlessly.
     let cmakelists_file_path = interface_directory.join("CMakeLists.txt");
-    if !cmakelists_file_path.exists() {
-        let template_cmakelists_file_path = template_directory
-            .join("miscellaneous")
-            .join("template_CMakeLists.txt");
-        std::fs::copy(&template_cmakelists_file_path, &cmakelists_file_path)?;
+    if !cmakelists_file_path.exists()
+        || std::fs::read_to_string(&cmakelists_file_path)? != cmakelists
+    {
+        std::fs::write(&cmakelists_file_path, &cmakelists)?;
     }
     Ok((interface_directory, src_directory, include_directory))
 }
 
-pub fn compile(interface_directory: &std::path::PathBuf) -> Result<std::path::PathBuf> {
-    log::info!("Compiling SDSL C shared library.");
-    let destination_path = cmake::build(&interface_directory);
-    let lib_path = destination_path.join("lib").join("libsdsl_c.so");
+/// Compiler options for the SDSL C library.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CompileOptions {
+    /// Flags appended to `CMAKE_CXX_FLAGS`.
+    pub cxx_flags: Vec<String>,
+    /// Flags which replace those of every CMake build type, if set.
+    pub build_type_flags: Option<String>,
+}
+
+pub fn compile(
+    specifications: &[Specification],
+    interface_directory: &std::path::Path,
+    out_directory: &std::path::Path,
+    sdsl_lite: &Option<super::sdsl_lite::SdslLite>,
+    compile_options: &CompileOptions,
+) -> Result<std::path::PathBuf> {
+    let target_os =
+        std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_else(|_| std::env::consts::OS.to_string());
+    let lib_directory = out_directory.join("lib");
+    let lib_path = if cfg!(feature = "static") {
+        lib_directory.join("libsdsl_c.a")
+    } else {
+        lib_directory.join(super::lib_file_name(&target_os))
+    };
+
+    let cache = super::cache::Cache::new(specifications, sdsl_lite, compile_options)?;
+    let cached = match &cache {
+        Some(cache) => cache.restore(&lib_directory)?,
+        None => false,
+    };
+    if !cached {
+        let mut config = cmake::Config::new(interface_directory);
+        config.out_dir(out_directory);
+        for flag in &compile_options.cxx_flags {
+            config.cxxflag(flag);
+        }
+        // The build type is chosen from the cargo profile. Its flags are replaced, whichever it is.
+        if let Some(build_type_flags) = &compile_options.build_type_flags {
+            for build_type in &["DEBUG", "RELEASE", "RELWITHDEBINFO", "MINSIZEREL"] {
+                config.define(format!("CMAKE_CXX_FLAGS_{}", build_type), build_type_flags);
+            }
+        }
+        if cfg!(feature = "static") {
+            log::info!("Compiling SDSL C static library.");
+            config.define("BUILD_SHARED_LIBS", "OFF");
+        } else {
+            log::info!("Compiling SDSL C shared library.");
+        }
+        // Each source file is compiled to its own object, in parallel with the jobs cargo allows. CMake's dependency
+        // tracking recompiles only the objects whose sources or headers changed, which write_if_changed preserves.
+        if let Ok(jobs) = std::env::var("NUM_JOBS") {
+            config.env("CMAKE_BUILD_PARALLEL_LEVEL", jobs);
+        }
+        let start = std::time::Instant::now();
+        config.build();
+        if cfg!(feature = "static") {
+            // The function table lists the functions of the compiled library. It is recompiled if they changed.
+            let registry_path = interface_directory
+                .join("src")
+                .join(super::registry::SOURCE_FILE_NAME);
+            if write_if_changed(&registry_path, &super::registry::source(&lib_path)?)? {
+                config.build();
+            }
+        }
+        log::info!("CMake build took {:?}.", start.elapsed());
+        if let Some(cache) = &cache {
+            cache.store(&lib_directory)?;
+        }
+    }
+
+    if cfg!(feature = "static") {
+        link_static(&lib_path, sdsl_lite)?;
+    } else if let Some(origin) = rpath_origin(&target_os) {
+        // Executables find the shared lib when it is copied to their directory, and otherwise in the build directory.
+        println!("cargo:rustc-link-arg=-Wl,-rpath,{}", origin);
+        println!(
+            "cargo:rustc-link-arg=-Wl,-rpath,{}",
+            lib_directory.display()
+        );
+    }
     Ok(lib_path)
 }
+
+/// Returns the rpath token which stands for the directory of the executable, if the target's linker supports rpaths.
+fn rpath_origin(target_os: &str) -> Option<&'static str> {
+    match target_os {
+        "macos" | "ios" => Some("@loader_path"),
+        "windows" => None,
+        _ => Some("$ORIGIN"),
+    }
+}
+
+/// Link the SDSL C static library, and the SDSL-lite static libraries, into the crate's executables.
+///
+/// Functions are found through the generated function table, whose symbol is bound at link time. The libraries are
+/// passed as link arguments, which follow the Rust crates on the linker command line, so that the linker resolves the
+/// references of this crate to the table.
+///
+/// Cargo passes link arguments only to the executables, tests and examples of the package whose build script emits
+/// them. The package which builds executables must therefore call `sdsl::build` itself.
+fn link_static(
+    lib_path: &std::path::Path,
+    sdsl_lite: &Option<super::sdsl_lite::SdslLite>,
+) -> Result<()> {
+    let lib_directory = lib_path.parent().ok_or(format_err!(
+        "Failed to find directory of library: {}",
+        lib_path.display()
+    ))?;
+    let mut lib_directories = vec![lib_directory.to_path_buf()];
+    if let Some(sdsl_lite) = sdsl_lite {
+        lib_directories.extend(sdsl_lite.lib_directories.clone());
+    }
+    println!("cargo:rustc-link-arg={}", lib_path.display());
+
+    // Archives are passed after the SDSL C library, so that the linker resolves its references.
+    for archive in &["libsdsl.a", "libdivsufsort.a", "libdivsufsort64.a"] {
+        let archive_path = lib_directories
+            .iter()
+            .map(|directory| directory.join(archive))
+            .find(|archive_path| archive_path.exists());
+        if let Some(archive_path) = archive_path {
+            println!("cargo:rustc-link-arg={}", archive_path.display());
+        }
+    }
+    println!("cargo:rustc-link-arg=-lstdc++");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_if_changed() -> Result<()> {
+        let directory =
+            std::env::temp_dir().join(format!("sdsl-test-sources-{}", std::process::id()));
+        let path = directory.join("src").join("int_vector.cpp");
+
+        let created = write_if_changed(&path, "int main() {}")?;
+        let unchanged = write_if_changed(&path, "int main() {}")?;
+        let changed = write_if_changed(&path, "int main() { return 0; }")?;
+        let result = std::fs::read_to_string(&path)?;
+        std::fs::remove_dir_all(&directory)?;
+
+        assert_eq!((created, unchanged, changed), (true, false, true));
+        let expected = "int main() { return 0; }";
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
@@ -1,19 +1,93 @@
 use anyhow::{format_err, Result};
 use std::io::Write;
 
+/// The sdsl-c template archive.
+pub static ARCHIVE: &[u8] = include_bytes!("sdsl-c-template.zip");
+
+/// C++ sources which sdsl-rs adds to the sdsl-c template: paths relative to the template directory and contents.
+pub static EXTENSIONS: &[(&str, &str)] = &[
+    (
+        "include/extensions/extension.hpp",
+        include_str!("extensions/include/extensions/extension.hpp"),
+    ),
+    (
+        "src/extensions/error.cpp",
+        include_str!("extensions/src/extensions/error.cpp"),
+    ),
+    (
+        "src/extensions/int_vector.cpp",
+        include_str!("extensions/src/extensions/int_vector.cpp"),
+    ),
+    (
+        "src/extensions/bit_vector.cpp",
+        include_str!("extensions/src/extensions/bit_vector.cpp"),
+    ),
+    (
+        "src/extensions/rrr_vector.cpp",
+        include_str!("extensions/src/extensions/rrr_vector.cpp"),
+    ),
+    (
+        "src/extensions/rank_support_v.cpp",
+        include_str!("extensions/src/extensions/rank_support_v.cpp"),
+    ),
+    (
+        "src/extensions/select_support_mcl.cpp",
+        include_str!("extensions/src/extensions/select_support_mcl.cpp"),
+    ),
+    (
+        "src/extensions/wavelet_tree.cpp",
+        include_str!("extensions/src/extensions/wavelet_tree.cpp"),
+    ),
+    (
+        "src/extensions/io.cpp",
+        include_str!("extensions/src/extensions/io.cpp"),
+    ),
+    (
+        "src/extensions/util.cpp",
+        include_str!("extensions/src/extensions/util.cpp"),
+    ),
+];
+
 pub fn setup(out_directory: &std::path::PathBuf) -> Result<std::path::PathBuf> {
     let template_directory = out_directory.join("sdsl-c-template");
-    if template_directory.exists() {
-        log::debug!("sdsl-c template setup complete.");
-        return Ok(template_directory);
+    if !template_directory.exists() {
+        extract_template(out_directory, &template_directory)?;
     }
+    setup_extensions(&template_directory)?;
+    log::debug!("sdsl-c template setup complete.");
+    Ok(template_directory)
+}
+
+/// Write the extension sources into the template directory.
+///
+/// Files are rewritten only if their contents changed, so that a template directory left by an earlier build picks
+/// up new extensions.
+fn setup_extensions(template_directory: &std::path::Path) -> Result<()> {
+    for (path, contents) in EXTENSIONS {
+        let path = template_directory.join(path);
+        if let Ok(existing_contents) = std::fs::read_to_string(&path) {
+            if existing_contents == *contents {
+                continue;
+            }
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, contents)?;
+    }
+    Ok(())
+}
+
+fn extract_template(
+    out_directory: &std::path::Path,
+    template_directory: &std::path::Path,
+) -> Result<()> {
     log::debug!("Setting up sdsl-c template.");
 
     let archive_path = out_directory.join("sdsl-c-template.zip");
-    let bytes = include_bytes!("sdsl-c-template.zip");
     {
         let mut file = std::fs::File::create(&archive_path)?;
-        file.write_all(bytes)?;
+        file.write_all(ARCHIVE)?;
         file.sync_all()?;
     }
 
@@ -29,8 +103,7 @@ pub fn setup(out_directory: &std::path::PathBuf) -> Result<std::path::PathBuf> {
 
     std::fs::rename(&top_level_directory, &template_directory)?;
     std::fs::remove_dir_all(&unpack_directory)?;
-
-    Ok(template_directory)
+    Ok(())
 }
 
 fn extract_zip(
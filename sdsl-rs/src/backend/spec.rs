@@ -0,0 +1,118 @@
+/// An SDSL structure type which should be included in the interface backend.
+///
+/// Construct with the [`spec!`](crate::spec) macro and pass to [`build_with`](crate::build_with).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Spec {
+    type_name: String,
+}
+
+impl Spec {
+    /// Construct a specification from a type path.
+    ///
+    /// Paths must be fully qualified as exported by this crate, such as `sdsl::bit_vectors::BitVector`.
+    /// # Arguments
+    /// * `type_name` - Type path including generic parameters.
+    pub fn new(type_name: &str) -> Self {
+        Self {
+            type_name: type_name.chars().filter(|c| !c.is_whitespace()).collect(),
+        }
+    }
+
+    /// Returns the type path without whitespace.
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// Returns the type as it is written in MIR: a type annotation without lifetimes and with suffixed integer
+    /// parameters.
+    pub(crate) fn mir(&self) -> String {
+        let mut mir = String::from(": ");
+        let mut token = String::new();
+        for c in self.type_name.chars() {
+            if c != '<' && c != '>' && c != ',' {
+                token.push(c);
+                continue;
+            }
+            // Lifetimes are erased in MIR.
+            if !token.starts_with('\'') {
+                mir.push_str(&mir_token(&token));
+                mir.push(c);
+            } else if c != ',' {
+                mir.push(c);
+            }
+            token.clear();
+        }
+        mir.push_str(&mir_token(&token));
+        mir.push(';');
+        mir
+    }
+}
+
+/// Integer parameters are written with a type suffix in MIR, e.g. `63_u16`.
+fn mir_token(token: &str) -> String {
+    if !token.is_empty() && token.chars().all(|c| c.is_ascii_digit()) {
+        format!("{}_", token)
+    } else {
+        token.to_string()
+    }
+}
+
+/// Construct a [`Spec`](crate::Spec) from a type.
+///
+/// # Example
+/// ```ignore
+/// // build.rs
+/// fn main() {
+///     let specs = [sdsl::spec!(
+///         sdsl::wavelet_trees::WtInt<sdsl::bit_vectors::BitVector>
+///     )];
+///     match sdsl::build_with(&specs) {
+///         Ok(_) => {}
+///         Err(e) => panic!("Error: {}", e),
+///     };
+/// }
+/// ```
+#[macro_export]
+macro_rules! spec {
+    ($type:ty) => {
+        $crate::Spec::new(stringify!($type))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mir_suffixes_integer_parameters() {
+        let spec =
+            crate::spec!(sdsl::bit_vectors::RrrVector<sdsl::int_vectors::IntVector<0>, 63, 32>);
+        let result = spec.mir();
+        let expected = ": sdsl::bit_vectors::RrrVector<sdsl::int_vectors::IntVector<0_>,63_,32_>;";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_mir_erases_lifetimes() {
+        let spec = crate::spec!(sdsl::rank_supports::RankSupportV<'static, sdsl::bit_patterns::P1>);
+        let result = spec.mir();
+        let expected = ": sdsl::rank_supports::RankSupportV<sdsl::bit_patterns::P1>;";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_mir_without_parameters() {
+        let spec = crate::spec!(sdsl::bit_vectors::BitVector);
+        let result = spec.mir();
+        let expected = ": sdsl::bit_vectors::BitVector;";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_type_name_strips_whitespace() {
+        let spec = Spec::new("sdsl::int_vectors::IntVector< 0 >");
+        let result = spec.type_name();
+        let expected = "sdsl::int_vectors::IntVector<0>";
+        assert_eq!(result, expected);
+    }
+}
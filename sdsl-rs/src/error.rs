@@ -0,0 +1,187 @@
+/// Errors returned by SDSL data structures.
+///
+/// New variants may be added in minor releases, so matches must include a wildcard arm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The compiled SDSL C interface library could not be loaded.
+    LibraryNotLoaded(String),
+
+    /// A function is missing from the compiled SDSL C interface library.
+    ///
+    /// Usually the structure was not instantiated when the library was built.
+    SymbolNotFound { name: String },
+
+    /// A structure type was not compiled into the SDSL C interface library.
+    ///
+    /// Usually the build script analysis missed the type. `type_name` is the Rust type.
+    NotInstantiated { type_name: String },
+
+    /// An IO operation failed, for example while reading a structure from a file.
+    ///
+    /// `source` is the underlying `std::io::Error`, if any.
+    Io {
+        kind: std::io::ErrorKind,
+        message: String,
+        source: Option<IoSource>,
+    },
+
+    /// An argument is not valid for the structure, for example a value wider than the vector width.
+    InvalidArgument(String),
+
+    /// The C++ library threw an exception.
+    CppException(String),
+
+    /// The C code specification of a structure type could not be generated.
+    Specification(String),
+
+    /// An index is outside of the range $ [0, \mathrm{len}) $.
+    IndexOutOfBounds { index: usize, len: usize },
+
+    /// A select query asked for an occurrence outside of the range $ [1, \mathrm{count}] $.
+    ///
+    /// `count` is `None` if the number of occurrences is unknown.
+    OccurrenceOutOfRange {
+        occurrence: usize,
+        count: Option<usize>,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::LibraryNotLoaded(reason) => {
+                write!(f, "Failed to load the SDSL C interface library: {}", reason)
+            }
+            Error::SymbolNotFound { name } => write!(
+                f,
+                "Function {} not found in the SDSL C interface library.",
+                name
+            ),
+            Error::NotInstantiated { type_name } => write!(
+                f,
+                "Structure type {type_name} was not compiled into the SDSL C interface library. \
+                 Register it in the build script: sdsl::build_with(&[sdsl::spec!({type_name})])",
+                type_name = type_name
+            ),
+            Error::Io { message, .. } => write!(f, "{}", message),
+            Error::InvalidArgument(message) => write!(f, "{}", message),
+            Error::CppException(message) => write!(f, "SDSL exception: {}", message),
+            Error::Specification(message) => {
+                write!(f, "Failed to generate structure specification: {}", message)
+            }
+            Error::IndexOutOfBounds { index, len } => {
+                write!(f, "Index {} out of bounds for length {}.", index, len)
+            }
+            Error::OccurrenceOutOfRange {
+                occurrence,
+                count: Some(count),
+            } => write!(
+                f,
+                "Occurrence {} out of range, expected an occurrence in [1, {}].",
+                occurrence, count
+            ),
+            Error::OccurrenceOutOfRange {
+                occurrence,
+                count: None,
+            } => write!(
+                f,
+                "Occurrence {} out of range, occurrences are numbered from 1.",
+                occurrence
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io {
+                source: Some(source),
+                ..
+            } => Some(source.io_error()),
+            _ => None,
+        }
+    }
+}
+
+/// The `std::io::Error` which caused an `Error::Io`.
+///
+/// Shared, so that `Error` remains `Clone`. Compares equal to errors of the same kind and message.
+#[derive(Debug, Clone)]
+pub struct IoSource(std::sync::Arc<std::io::Error>);
+
+impl IoSource {
+    pub fn io_error(&self) -> &std::io::Error {
+        &self.0
+    }
+}
+
+impl PartialEq for IoSource {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.kind() == other.0.kind() && self.0.to_string() == other.0.to_string()
+    }
+}
+
+impl Eq for IoSource {}
+
+impl Error {
+    /// Construct an IO error of the given kind.
+    pub(crate) fn io(kind: std::io::ErrorKind, message: String) -> Self {
+        Error::Io {
+            kind,
+            message,
+            source: None,
+        }
+    }
+
+    /// Construct an IO error caused by `error`, described by `message`.
+    pub(crate) fn io_caused_by(error: std::io::Error, message: String) -> Self {
+        Error::Io {
+            kind: error.kind(),
+            message,
+            source: Some(IoSource(std::sync::Arc::new(error))),
+        }
+    }
+
+    /// Convert a failure to generate C code for a structure type.
+    pub(crate) fn specification(error: anyhow::Error) -> Self {
+        Error::Specification(error.to_string())
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        let message = error.to_string();
+        Error::io_caused_by(error, message)
+    }
+}
+
+impl From<std::ffi::NulError> for Error {
+    fn from(error: std::ffi::NulError) -> Self {
+        Error::InvalidArgument(format!("String contains a nul byte: {}", error))
+    }
+}
+
+/// Result type of SDSL data structure operations.
+pub type Result<T> = std::result::Result<T, Error>;
+
+pub(crate) fn check_index(index: usize, len: usize) -> Result<()> {
+    if index < len {
+        Ok(())
+    } else {
+        Err(Error::IndexOutOfBounds { index, len })
+    }
+}
+
+pub(crate) fn check_occurrence(occurrence: usize, count: Option<usize>) -> Result<()> {
+    let in_range = match count {
+        Some(count) => occurrence >= 1 && occurrence <= count,
+        None => occurrence >= 1,
+    };
+    if in_range {
+        Ok(())
+    } else {
+        Err(Error::OccurrenceOutOfRange { occurrence, count })
+    }
+}
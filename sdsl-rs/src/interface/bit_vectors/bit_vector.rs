@@ -1,6 +1,6 @@
 use crate::backend::sdsl_c;
+use crate::error::{Error, Result};
 use crate::meta;
-use anyhow::{format_err, Result};
 
 use crate::interface::common::{self, Code, Id};
 
@@ -17,6 +17,7 @@ use crate::interface::common::{self, Code, Id};
 /// For further examples see [here](https://github.com/sdsl-rs/sdsl-rs/blob/master/examples/src/bit_vectors/bit_vector.rs).
 pub struct BitVector {
     ptr: common::VoidPtr,
+    len_cache: common::LenCache,
     interface: Interface,
 }
 
@@ -29,40 +30,86 @@ impl BitVector {
         let id = Self::id()?;
         let interface = Interface::new(&id)?;
         let ptr = (interface.create)(size, default_value);
+        sdsl_c::check_last_error()?;
 
-        Ok(Self { ptr, interface })
+        Ok(Self {
+            ptr,
+            len_cache: common::LenCache::default(),
+            interface,
+        })
     }
 
     /// Load vector from file.
     /// # Arguments
     /// * `path` - File path.
     pub fn from_file(path: &std::path::PathBuf) -> Result<Self> {
-        let vector = Self::new(1, 0)?;
+        let mut vector = Self::new(1, 0)?;
+        common::io::load_from_file(&mut vector, path)?;
+        Ok(vector)
+    }
 
-        let path = path
-            .to_str()
-            .ok_or(format_err!("Failed to convert PathBuf into str."))?;
-        let path = std::ffi::CString::new(path)?;
+    /// Write the vector to a stream in SDSL's serialization format.
+    ///
+    /// Returns the number of bytes written.
+    /// # Arguments
+    /// * `writer` - Destination stream.
+    pub fn serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<usize> {
+        common::io::serialize(self, writer)
+    }
 
-        (vector.interface.io.load_from_file)(vector.ptr, path.as_ptr());
+    /// Load a vector from a stream in SDSL's serialization format.
+    /// # Arguments
+    /// * `reader` - Source stream.
+    pub fn load<R: std::io::Read>(reader: &mut R) -> Result<Self> {
+        let mut vector = Self::new(1, 0)?;
+        common::io::load(&mut vector, reader)?;
         Ok(vector)
     }
 
+    /// Returns the vector in SDSL's serialization format.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        common::io::to_bytes(self)
+    }
+
+    /// Load a vector from a buffer in SDSL's serialization format.
+    /// # Arguments
+    /// * `bytes` - Serialized vector.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut vector = Self::new(1, 0)?;
+        common::io::load_from_bytes(&mut vector, bytes)?;
+        Ok(vector)
+    }
+
+    /// Returns a copy of the vector, or an error if SDSL fails to allocate it.
+    pub fn try_clone(&self) -> Result<Self> {
+        let ptr = (self.interface.clone)(self.ptr);
+        sdsl_c::check_last_error()?;
+        Ok(Self {
+            ptr,
+            len_cache: common::LenCache::default(),
+            interface: self.interface.clone(),
+        })
+    }
+
     /// Returns true if the vector is empty, otherwise returns false.
     pub fn is_empty(&self) -> bool {
         (self.interface.is_empty)(self.ptr)
     }
 
     /// Resize the vector in terms of elements.
+    ///
+    /// Returns an error if SDSL fails to allocate the storage.
     /// # Arguments
     /// * `size` - Target number of elements.
-    pub fn resize(&mut self, size: usize) {
-        (self.interface.resize)(self.ptr, size)
+    pub fn resize(&mut self, size: usize) -> Result<()> {
+        (self.interface.resize)(self.ptr, size);
+        self.len_cache.invalidate();
+        sdsl_c::check_last_error()
     }
 
     /// The number of elements in the vector.
     pub fn len(&self) -> usize {
-        (self.interface.len)(self.ptr)
+        self.len_cache.get(|| (self.interface.len)(self.ptr))
     }
 
     /// Maximum size of the vector.
@@ -120,29 +167,188 @@ impl BitVector {
     }
 
     /// Get the i-th element of the vector.
+    ///
+    /// Panics if `index` is out of bounds, see `try_get` for a checked alternative.
     /// # Arguments
     /// * `index` - An index in range $ [0, \mathrm{len}()) $.
     pub fn get(&self, index: usize) -> u8 {
+        self.try_get(index)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Get the i-th element of the vector or an error if `index` is out of bounds.
+    /// # Arguments
+    /// * `index` - An index in range $ [0, \mathrm{len}()) $.
+    pub fn try_get(&self, index: usize) -> Result<u8> {
+        crate::error::check_index(index, self.len())?;
+        Ok(unsafe { self.get_unchecked(index) })
+    }
+
+    /// Get the i-th element of the vector without bounds checking.
+    /// # Safety
+    /// `index` must be in range $ [0, \mathrm{len}()) $, otherwise memory outside of the vector is read.
+    /// # Arguments
+    /// * `index` - An index in range $ [0, \mathrm{len}()) $.
+    pub unsafe fn get_unchecked(&self, index: usize) -> u8 {
         (self.interface.get)(self.ptr, index)
     }
 
     /// Set the i-th element of the vector.
+    ///
+    /// Panics if `index` is out of bounds.
     /// # Arguments
     /// * `index` - An index in range $ [0, \mathrm{len}()) $.
     /// * `value` - New element value.
     pub fn set(&mut self, index: usize, value: usize) {
+        if let Err(error) = crate::error::check_index(index, self.len()) {
+            panic!("{}", error);
+        }
+        unsafe { self.set_unchecked(index, value) }
+    }
+
+    /// Set the i-th element of the vector without bounds checking.
+    /// # Safety
+    /// `index` must be in range $ [0, \mathrm{len}()) $, otherwise memory outside of the vector is written.
+    /// # Arguments
+    /// * `index` - An index in range $ [0, \mathrm{len}()) $.
+    /// * `value` - New element value.
+    pub unsafe fn set_unchecked(&mut self, index: usize, value: usize) {
         (self.interface.set)(self.ptr, index, value)
     }
 
+    /// Copy consecutive elements of the vector into a buffer using a single FFI call.
+    /// # Arguments
+    /// * `start` - Index of the first element to copy.
+    /// * `values` - Buffer to fill. Elements $ [\mathrm{start}, \mathrm{start} + \mathrm{values.len}()) $ must be within the vector.
+    pub fn get_range(&self, start: usize, values: &mut [u8]) {
+        assert!(
+            start + values.len() <= self.len(),
+            "Range end {} exceeds vector length {}.",
+            start + values.len(),
+            self.len()
+        );
+        (self.interface.get_range)(self.ptr, start, values.len(), values.as_mut_ptr())
+    }
+
+    /// Overwrite consecutive elements of the vector using a single FFI call.
+    /// # Arguments
+    /// * `start` - Index of the first element to overwrite.
+    /// * `values` - New element values. Elements $ [\mathrm{start}, \mathrm{start} + \mathrm{values.len}()) $ must be within the vector.
+    pub fn set_range(&mut self, start: usize, values: &[u8]) {
+        assert!(
+            start + values.len() <= self.len(),
+            "Range end {} exceeds vector length {}.",
+            start + values.len(),
+            self.len()
+        );
+        (self.interface.set_range)(self.ptr, start, values.len(), values.as_ptr())
+    }
+
     /// Flip all bits.
     pub fn flip(&mut self) {
         (self.interface.flip)(self.ptr)
     }
 
+    /// Returns the number of set bits in the vector.
+    pub fn count_ones(&self) -> usize {
+        (self.interface.count_ones)(self.ptr)
+    }
+
+    /// Returns the number of unset bits in the vector.
+    pub fn count_zeros(&self) -> usize {
+        self.len() - self.count_ones()
+    }
+
+    /// Returns true if every set bit of this vector is also set in `other`, otherwise returns false.
+    ///
+    /// Panics if the vectors differ in length.
+    /// # Arguments
+    /// * `other` - Bit vector of equal length.
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        self.assert_same_len(other);
+        (self.interface.is_subset_of)(self.ptr, other.ptr)
+    }
+
+    /// Returns a new vector with the bits which are set in this vector but not in `other`.
+    ///
+    /// Panics if the vectors differ in length.
+    /// # Arguments
+    /// * `other` - Bit vector of equal length.
+    pub fn and_not(&self, other: &Self) -> Self {
+        self.assert_same_len(other);
+        let vector = self.clone();
+        (self.interface.and_not_assign)(vector.ptr, other.ptr);
+        vector
+    }
+
+    /// Returns the position of the first set bit at or after `position`, or `None` if there is none.
+    /// # Arguments
+    /// * `position` - Start position of the search.
+    pub fn next_one(&self, position: usize) -> Option<usize> {
+        common::bit_scan::next_one(self, position)
+    }
+
+    /// Returns the position of the last set bit at or before `position`, or `None` if there is none.
+    /// # Arguments
+    /// * `position` - Start position of the search.
+    pub fn prev_one(&self, position: usize) -> Option<usize> {
+        common::bit_scan::prev_one(self, position)
+    }
+
+    /// Returns the position of the first unset bit at or after `position`, or `None` if there is none.
+    /// # Arguments
+    /// * `position` - Start position of the search.
+    pub fn next_zero(&self, position: usize) -> Option<usize> {
+        common::bit_scan::next_zero(self, position)
+    }
+
+    /// Returns the position of the last unset bit at or before `position`, or `None` if there is none.
+    /// # Arguments
+    /// * `position` - Start position of the search.
+    pub fn prev_zero(&self, position: usize) -> Option<usize> {
+        common::bit_scan::prev_zero(self, position)
+    }
+
+    /// Returns an iterator over the positions of set bits.
+    ///
+    /// Bits are read 64 at a time.
+    pub fn ones(&self) -> common::bit_scan::Ones<'_, Self> {
+        common::bit_scan::Ones::new(self)
+    }
+
+    fn assert_same_len(&self, other: &Self) {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "Bitwise operations require bit vectors of equal length."
+        );
+    }
+
     /// Returns an iterator over the vector values.
-    pub fn iter(&self) -> common::VectorIterator<u8, Self> {
+    pub fn iter(&self) -> common::VectorIterator<'_, u8, Self> {
         common::VectorIterator::new(&self, self.len())
     }
+
+    /// Returns a read-only view of the elements with indexes in `range`.
+    ///
+    /// Panics if `range` is out of bounds.
+    /// # Arguments
+    /// * `range` - Index range within $ [0, \mathrm{len}()) $.
+    pub fn slice(&self, range: std::ops::Range<usize>) -> common::VectorSlice<'_, u8, Self> {
+        common::VectorSlice::new(self, self.len(), range)
+    }
+}
+
+impl std::ops::Index<usize> for BitVector {
+    type Output = bool;
+
+    fn index(&self, index: usize) -> &bool {
+        if self.get(index) == 1 {
+            &true
+        } else {
+            &false
+        }
+    }
 }
 
 impl common::util::Util for BitVector {
@@ -161,14 +367,17 @@ impl common::Ptr for BitVector {
     fn ptr(&self) -> &common::VoidPtr {
         &self.ptr
     }
+
+    fn ptr_mut(&mut self) -> &common::VoidPtr {
+        self.len_cache.invalidate();
+        &self.ptr
+    }
 }
 
 impl common::Id for BitVector {
     fn id() -> Result<String> {
-        let meta = Box::new(meta::bit_vectors::bit_vector::BitVectorMeta::new())
-            as Box<dyn meta::common::Meta>;
-        let parameters_c_code = Self::parameters_c_code()?;
-        let id = sdsl_c::specification::get_id(&meta.c_code(&parameters_c_code)?)?;
+        let id = sdsl_c::specification::get_id(&Self::c_code()?).map_err(Error::specification)?;
+        sdsl_c::manifest::check_instantiation(&id, std::any::type_name::<Self>())?;
         Ok(id)
     }
 }
@@ -178,7 +387,8 @@ impl common::Code for BitVector {
         let meta = Box::new(meta::bit_vectors::bit_vector::BitVectorMeta::new())
             as Box<dyn meta::common::Meta>;
         let parameters_c_code = Self::parameters_c_code()?;
-        Ok(meta.c_code(&parameters_c_code)?)
+        meta.c_code(&parameters_c_code)
+            .map_err(Error::specification)
     }
 
     fn parameters_c_code() -> Result<Vec<String>> {
@@ -186,10 +396,47 @@ impl common::Code for BitVector {
     }
 }
 
+impl common::bit_scan::WordGet for BitVector {
+    fn bit_len(&self) -> usize {
+        self.len()
+    }
+
+    fn get_word(&self, index: usize, len: u8) -> u64 {
+        self.get_int(index, len) as u64
+    }
+}
+
 impl common::IterGet<u8> for BitVector {
     fn iter_get(&self, index: usize) -> u8 {
         (self.interface.get)(self.ptr, index)
     }
+
+    fn iter_get_range(&self, index: usize, len: usize, values: &mut Vec<u8>) {
+        values.clear();
+        values.resize(len, 0);
+        (self.interface.get_range)(self.ptr, index, len, values.as_mut_ptr());
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for BitVector {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        let bytes = self.to_bytes().map_err(serde::ser::Error::custom)?;
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BitVector {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let bytes = common::io::deserialize_bytes(deserializer)?;
+        Self::from_bytes(&bytes).map_err(serde::de::Error::custom)
+    }
 }
 
 impl Drop for BitVector {
@@ -199,11 +446,10 @@ impl Drop for BitVector {
 }
 
 impl Clone for BitVector {
+    /// Panics if SDSL fails to allocate the copy, see `try_clone`.
     fn clone(&self) -> Self {
-        Self {
-            ptr: (self.interface.clone)(self.ptr),
-            interface: self.interface.clone(),
-        }
+        self.try_clone()
+            .unwrap_or_else(|error| panic!("Failed to clone BitVector: {}", error))
     }
 }
 
@@ -237,6 +483,93 @@ impl IntoIterator for BitVector {
     }
 }
 
+impl std::iter::FromIterator<bool> for BitVector {
+    fn from_iter<T: IntoIterator<Item = bool>>(iter: T) -> Self {
+        let values: Vec<bool> = iter.into_iter().collect();
+        Self::from(values)
+    }
+}
+
+impl From<Vec<bool>> for BitVector {
+    fn from(values: Vec<bool>) -> Self {
+        let values: Vec<u8> = values.into_iter().map(u8::from).collect();
+        let mut vector =
+            Self::new(values.len(), 0).expect("Failed to construct BitVector from values.");
+        vector.set_range(0, &values);
+        vector
+    }
+}
+
+impl Extend<bool> for BitVector {
+    fn extend<T: IntoIterator<Item = bool>>(&mut self, iter: T) {
+        let values: Vec<u8> = iter.into_iter().map(u8::from).collect();
+        let len = self.len();
+        if let Err(error) = self.resize(len + values.len()) {
+            panic!("Failed to extend BitVector: {}", error);
+        }
+        self.set_range(len, &values);
+    }
+}
+
+macro_rules! impl_bitwise_operator {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $function:ident) => {
+        impl std::ops::$assign_trait<&BitVector> for BitVector {
+            fn $assign_method(&mut self, other: &BitVector) {
+                self.assert_same_len(other);
+                (self.interface.$function)(self.ptr, other.ptr)
+            }
+        }
+
+        impl std::ops::$assign_trait<BitVector> for BitVector {
+            fn $assign_method(&mut self, other: BitVector) {
+                std::ops::$assign_trait::$assign_method(self, &other)
+            }
+        }
+
+        impl std::ops::$trait<&BitVector> for &BitVector {
+            type Output = BitVector;
+
+            fn $method(self, other: &BitVector) -> BitVector {
+                let mut vector = self.clone();
+                std::ops::$assign_trait::$assign_method(&mut vector, other);
+                vector
+            }
+        }
+
+        impl std::ops::$trait<BitVector> for BitVector {
+            type Output = BitVector;
+
+            fn $method(mut self, other: BitVector) -> BitVector {
+                std::ops::$assign_trait::$assign_method(&mut self, &other);
+                self
+            }
+        }
+    };
+}
+
+impl_bitwise_operator!(BitAnd, bitand, BitAndAssign, bitand_assign, bit_and_assign);
+impl_bitwise_operator!(BitOr, bitor, BitOrAssign, bitor_assign, bit_or_assign);
+impl_bitwise_operator!(BitXor, bitxor, BitXorAssign, bitxor_assign, bit_xor_assign);
+
+impl std::ops::Not for &BitVector {
+    type Output = BitVector;
+
+    fn not(self) -> BitVector {
+        let mut vector = self.clone();
+        vector.flip();
+        vector
+    }
+}
+
+impl std::ops::Not for BitVector {
+    type Output = BitVector;
+
+    fn not(mut self) -> BitVector {
+        self.flip();
+        self
+    }
+}
+
 #[derive(Clone)]
 struct Interface {
     create: extern "C" fn(usize, usize) -> common::VoidPtr,
@@ -256,28 +589,36 @@ struct Interface {
     set_int: extern "C" fn(common::VoidPtr, usize, usize, u8),
     get: extern "C" fn(common::VoidPtr, usize) -> u8,
     set: extern "C" fn(common::VoidPtr, usize, usize),
+    get_range: extern "C" fn(common::VoidPtr, usize, usize, *mut u8),
+    set_range: extern "C" fn(common::VoidPtr, usize, usize, *const u8),
 
     equality: extern "C" fn(common::VoidPtr, common::VoidPtr) -> bool,
 
     flip: extern "C" fn(common::VoidPtr),
+    bit_and_assign: extern "C" fn(common::VoidPtr, common::VoidPtr),
+    bit_or_assign: extern "C" fn(common::VoidPtr, common::VoidPtr),
+    bit_xor_assign: extern "C" fn(common::VoidPtr, common::VoidPtr),
+    and_not_assign: extern "C" fn(common::VoidPtr, common::VoidPtr),
+    count_ones: extern "C" fn(common::VoidPtr) -> usize,
+    is_subset_of: extern "C" fn(common::VoidPtr, common::VoidPtr) -> bool,
 
     pub io: common::io::Interface,
     util: common::util::Interface,
-    _lib: std::sync::Arc<sharedlib::Lib>,
+    _lib: std::sync::Arc<sdsl_c::Library>,
 }
 
 impl Interface {
     pub fn new(id: &str) -> Result<Self> {
-        let lib = sdsl_c::LIB.clone();
+        let lib = sdsl_c::lib()?;
         let builder = sdsl_c::FunctionBuilder::new(Some("bit_vector"), id, lib.clone());
 
         Ok(Self {
-            create: builder.get("create")?,
+            create: builder.get("try_create")?,
             drop: builder.get("destroy")?,
-            clone: builder.get("copy")?,
+            clone: builder.get("try_copy")?,
             is_empty: builder.get("empty")?,
 
-            resize: builder.get("resize")?,
+            resize: builder.get("try_resize")?,
             len: builder.get("size")?,
             max_size: builder.get("max_size")?,
             bit_size: builder.get("bit_size")?,
@@ -289,10 +630,18 @@ impl Interface {
             set_int: builder.get("set_int")?,
             get: builder.get("get_element")?,
             set: builder.get("set_element")?,
+            get_range: builder.get("get_range")?,
+            set_range: builder.get("set_range")?,
 
             equality: builder.get("equality_operator")?,
 
             flip: builder.get("flip")?,
+            bit_and_assign: builder.get("bit_and_assign")?,
+            bit_or_assign: builder.get("bit_or_assign")?,
+            bit_xor_assign: builder.get("bit_xor_assign")?,
+            and_not_assign: builder.get("and_not_assign")?,
+            count_ones: builder.get("count_ones")?,
+            is_subset_of: builder.get("is_subset_of")?,
 
             io: common::io::Interface::new(&id)?,
             util: common::util::Interface::new(&id)?,
@@ -317,11 +666,7 @@ macro_rules! bit_vector {
         {
             let _size = bit_vector!(@count $($key),*);
             let mut _vec = sdsl::bit_vectors::BitVector::new(_size, 0)?;
-            let mut i = 0;
-            $(
-                _vec.set(i, $key);
-                i += 1;
-            )*
+            _vec.set_range(0, &[$(($key) as u8),*]);
             _vec
         }
     };
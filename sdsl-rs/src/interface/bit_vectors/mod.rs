@@ -3,4 +3,5 @@ pub mod rrr_vector;
 
 pub mod crate_export {
     pub use super::{bit_vector::BitVector, rrr_vector::RrrVector};
+    pub use crate::interface::common::bit_scan::Ones;
 }
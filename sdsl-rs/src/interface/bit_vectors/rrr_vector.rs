@@ -1,6 +1,6 @@
+use crate::error::{Error, Result};
 use crate::meta;
 use crate::{backend::sdsl_c, interface::common::Ptr};
-use anyhow::{format_err, Result};
 
 use crate::interface::common::{self, Code, Id};
 
@@ -48,6 +48,7 @@ where
     // Dummy field so BlockStore is used, always None.
     _bs: &'a Option<BlockStore>,
     ptr: common::VoidPtr,
+    len_cache: common::LenCache,
     interface: Interface,
 }
 
@@ -63,10 +64,12 @@ where
         let id = Self::id()?;
         let interface = Interface::new(&id)?;
         let ptr = (interface.create)(*bit_vector.ptr());
+        sdsl_c::check_last_error()?;
 
         Ok(Self {
             _bs: &None,
             ptr,
+            len_cache: common::LenCache::default(),
             interface,
         })
     }
@@ -75,38 +78,103 @@ where
     /// # Arguments
     /// * `path` - File path.
     pub fn from_file(path: &std::path::PathBuf) -> Result<Self> {
-        let rrr_vector = Self::default()?;
+        let mut rrr_vector = Self::default()?;
+        common::io::load_from_file(&mut rrr_vector, path)?;
+        Ok(rrr_vector)
+    }
 
-        let path = path
-            .to_str()
-            .ok_or(format_err!("Failed to convert PathBuf into str."))?;
-        let path = std::ffi::CString::new(path)?;
+    /// Write the vector to a stream in SDSL's serialization format.
+    ///
+    /// Returns the number of bytes written.
+    /// # Arguments
+    /// * `writer` - Destination stream.
+    pub fn serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<usize> {
+        common::io::serialize(self, writer)
+    }
 
-        (rrr_vector.interface.io.load_from_file)(rrr_vector.ptr, path.as_ptr());
-        Ok(rrr_vector)
+    /// Load a vector from a stream in SDSL's serialization format.
+    /// # Arguments
+    /// * `reader` - Source stream.
+    pub fn load<R: std::io::Read>(reader: &mut R) -> Result<Self> {
+        let mut vector = Self::default()?;
+        common::io::load(&mut vector, reader)?;
+        Ok(vector)
+    }
+
+    /// Returns the vector in SDSL's serialization format.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        common::io::to_bytes(self)
+    }
+
+    /// Load a vector from a buffer in SDSL's serialization format.
+    /// # Arguments
+    /// * `bytes` - Serialized vector.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut vector = Self::default()?;
+        common::io::load_from_bytes(&mut vector, bytes)?;
+        Ok(vector)
+    }
+
+    /// Returns a copy of the vector, or an error if SDSL fails to allocate it.
+    pub fn try_clone(&self) -> Result<Self> {
+        let ptr = (self.interface.clone)(self.ptr);
+        sdsl_c::check_last_error()?;
+        Ok(Self {
+            _bs: &None,
+            ptr,
+            len_cache: common::LenCache::default(),
+            interface: self.interface.clone(),
+        })
     }
 
     fn default() -> Result<Self> {
         let id = Self::id()?;
         let interface = Interface::new(&id)?;
         let ptr = (interface.default)();
+        sdsl_c::check_last_error()?;
 
         Ok(Self {
             _bs: &None,
             ptr,
+            len_cache: common::LenCache::default(),
             interface,
         })
     }
 
     /// Returns the length of the original bit vector.
     pub fn len(&self) -> usize {
-        (self.interface.len)(self.ptr)
+        self.len_cache.get(|| (self.interface.len)(self.ptr))
+    }
+
+    /// Returns true if the original bit vector is empty, otherwise returns false.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
     /// Get the i-th element of the original bit vector.
+    ///
+    /// Panics if `index` is out of bounds, see `try_get_bv_element` for a checked alternative.
     /// # Arguments
     /// * `index` - An index in range $ [0, \mathrm{len}()) $.
     pub fn get_bv_element(&self, index: usize) -> usize {
+        self.try_get_bv_element(index)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Get the i-th element of the original bit vector or an error if `index` is out of bounds.
+    /// # Arguments
+    /// * `index` - An index in range $ [0, \mathrm{len}()) $.
+    pub fn try_get_bv_element(&self, index: usize) -> Result<usize> {
+        crate::error::check_index(index, self.len())?;
+        Ok(unsafe { self.get_bv_element_unchecked(index) })
+    }
+
+    /// Get the i-th element of the original bit vector without bounds checking.
+    /// # Safety
+    /// `index` must be in range $ [0, \mathrm{len}()) $, otherwise memory outside of the vector is read.
+    /// # Arguments
+    /// * `index` - An index in range $ [0, \mathrm{len}()) $.
+    pub unsafe fn get_bv_element_unchecked(&self, index: usize) -> usize {
         (self.interface.get_bv_element)(self.ptr, index)
     }
 
@@ -119,16 +187,76 @@ where
     }
 
     /// Returns an iterator over the original bit vector values.
-    pub fn iter_bv(&self) -> common::VectorIterator<usize, Self> {
+    pub fn iter_bv(&self) -> common::VectorIterator<'_, usize, Self> {
         common::VectorIterator::new(&self, self.len())
     }
 
     /// Returns an iterator over integer values of the binary string.
     /// # Arguments
     /// * `len` - Length of the binary representation of the integer. $ \mathrm{len} \in [1..64] $
-    pub fn iter_int(&self, len: u8) -> RrrVectorIntIterator<Self> {
+    pub fn iter_int(&self, len: u8) -> RrrVectorIntIterator<'_, Self> {
         RrrVectorIntIterator::new(&self, self.len(), len)
     }
+
+    /// Returns the position of the first set bit at or after `position`, or `None` if there is none.
+    /// # Arguments
+    /// * `position` - Start position of the search.
+    pub fn next_one(&self, position: usize) -> Option<usize> {
+        common::bit_scan::next_one(self, position)
+    }
+
+    /// Returns the position of the last set bit at or before `position`, or `None` if there is none.
+    /// # Arguments
+    /// * `position` - Start position of the search.
+    pub fn prev_one(&self, position: usize) -> Option<usize> {
+        common::bit_scan::prev_one(self, position)
+    }
+
+    /// Returns the position of the first unset bit at or after `position`, or `None` if there is none.
+    /// # Arguments
+    /// * `position` - Start position of the search.
+    pub fn next_zero(&self, position: usize) -> Option<usize> {
+        common::bit_scan::next_zero(self, position)
+    }
+
+    /// Returns the position of the last unset bit at or before `position`, or `None` if there is none.
+    /// # Arguments
+    /// * `position` - Start position of the search.
+    pub fn prev_zero(&self, position: usize) -> Option<usize> {
+        common::bit_scan::prev_zero(self, position)
+    }
+
+    /// Returns an iterator over the positions of set bits.
+    ///
+    /// Bits are read 64 at a time.
+    pub fn ones(&self) -> common::bit_scan::Ones<'_, Self> {
+        common::bit_scan::Ones::new(self)
+    }
+
+    /// Returns a read-only view of the original bit vector elements with indexes in `range`.
+    ///
+    /// Panics if `range` is out of bounds.
+    /// # Arguments
+    /// * `range` - Index range within $ [0, \mathrm{len}()) $.
+    pub fn slice(&self, range: std::ops::Range<usize>) -> common::VectorSlice<'_, usize, Self> {
+        common::VectorSlice::new(self, self.len(), range)
+    }
+}
+
+impl<'a, BlockStore, const BLOCK_SIZE: u16, const RANK_STORE_FREQ: u16> std::ops::Index<usize>
+    for RrrVector<'a, BlockStore, BLOCK_SIZE, RANK_STORE_FREQ>
+where
+    BlockStore: common::Code,
+{
+    type Output = bool;
+
+    fn index(&self, index: usize) -> &bool {
+        if self.get_bv_element(index) == 1 {
+            &true
+        } else {
+            &false
+        }
+    }
 }
 
 impl<'a, BlockStore, const BLOCK_SIZE: u16, const RANK_STORE_FREQ: u16> common::io::IO
@@ -149,6 +277,11 @@ where
     fn ptr(&self) -> &common::VoidPtr {
         &self.ptr
     }
+
+    fn ptr_mut(&mut self) -> &common::VoidPtr {
+        self.len_cache.invalidate();
+        &self.ptr
+    }
 }
 
 impl<'a, BlockStore, const BLOCK_SIZE: u16, const RANK_STORE_FREQ: u16> common::Id
@@ -157,10 +290,8 @@ where
     BlockStore: common::Code + 'a,
 {
     fn id() -> Result<String> {
-        let meta = Box::new(meta::bit_vectors::rrr_vector::RrrVectorMeta::new())
-            as Box<dyn meta::common::Meta>;
-        let parameters_c_code = Self::parameters_c_code()?;
-        let id = sdsl_c::specification::get_id(&meta.c_code(&parameters_c_code)?)?;
+        let id = sdsl_c::specification::get_id(&Self::c_code()?).map_err(Error::specification)?;
+        sdsl_c::manifest::check_instantiation(&id, std::any::type_name::<Self>())?;
         Ok(id)
     }
 }
@@ -174,7 +305,8 @@ where
         let meta = Box::new(meta::bit_vectors::rrr_vector::RrrVectorMeta::new())
             as Box<dyn meta::common::Meta>;
         let parameters_c_code = Self::parameters_c_code()?;
-        Ok(meta.c_code(&parameters_c_code)?)
+        meta.c_code(&parameters_c_code)
+            .map_err(Error::specification)
     }
 
     fn parameters_c_code() -> Result<Vec<String>> {
@@ -186,6 +318,20 @@ where
     }
 }
 
+impl<'a, BlockStore, const BLOCK_SIZE: u16, const RANK_STORE_FREQ: u16> common::bit_scan::WordGet
+    for RrrVector<'a, BlockStore, BLOCK_SIZE, RANK_STORE_FREQ>
+where
+    BlockStore: common::Code,
+{
+    fn bit_len(&self) -> usize {
+        self.len()
+    }
+
+    fn get_word(&self, index: usize, len: u8) -> u64 {
+        self.get_int(index, len) as u64
+    }
+}
+
 impl<'a, BlockStore, const BLOCK_SIZE: u16, const RANK_STORE_FREQ: u16> common::IterGet<usize>
     for RrrVector<'a, BlockStore, BLOCK_SIZE, RANK_STORE_FREQ>
 where
@@ -196,6 +342,35 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'a, BlockStore, const BLOCK_SIZE: u16, const RANK_STORE_FREQ: u16> serde::Serialize
+    for RrrVector<'a, BlockStore, BLOCK_SIZE, RANK_STORE_FREQ>
+where
+    BlockStore: common::Code,
+{
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        let bytes = self.to_bytes().map_err(serde::ser::Error::custom)?;
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, 'a, BlockStore, const BLOCK_SIZE: u16, const RANK_STORE_FREQ: u16> serde::Deserialize<'de>
+    for RrrVector<'a, BlockStore, BLOCK_SIZE, RANK_STORE_FREQ>
+where
+    BlockStore: common::Code,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let bytes = common::io::deserialize_bytes(deserializer)?;
+        Self::from_bytes(&bytes).map_err(serde::de::Error::custom)
+    }
+}
+
 impl<'a, BlockStore, const BLOCK_SIZE: u16, const RANK_STORE_FREQ: u16> Drop
     for RrrVector<'a, BlockStore, BLOCK_SIZE, RANK_STORE_FREQ>
 where
@@ -211,12 +386,10 @@ impl<'a, BlockStore, const BLOCK_SIZE: u16, const RANK_STORE_FREQ: u16> Clone
 where
     BlockStore: common::Code,
 {
+    /// Panics if SDSL fails to allocate the copy, see `try_clone`.
     fn clone(&self) -> Self {
-        Self {
-            _bs: &None,
-            ptr: (self.interface.clone)(self.ptr),
-            interface: self.interface.clone(),
-        }
+        self.try_clone()
+            .unwrap_or_else(|error| panic!("Failed to clone RrrVector: {}", error))
     }
 }
 
@@ -231,19 +404,19 @@ struct Interface {
     get_int: extern "C" fn(common::VoidPtr, usize, u8) -> usize,
 
     pub io: common::io::Interface,
-    _lib: std::sync::Arc<sharedlib::Lib>,
+    _lib: std::sync::Arc<sdsl_c::Library>,
 }
 
 impl Interface {
     pub fn new(id: &str) -> Result<Self> {
-        let lib = sdsl_c::LIB.clone();
+        let lib = sdsl_c::lib()?;
         let builder = sdsl_c::FunctionBuilder::new(Some("rrr_vector"), id, lib.clone());
 
         Ok(Self {
-            create: builder.get("create")?,
-            default: builder.get("default")?,
+            create: builder.get("try_create")?,
+            default: builder.get("try_default")?,
             drop: builder.get("destroy")?,
-            clone: builder.get("copy")?,
+            clone: builder.get("try_copy")?,
             len: builder.get("size")?,
             get_bv_element: builder.get("get_bv_element")?,
             get_int: builder.get("get_int")?,
@@ -1,5 +1,5 @@
+use crate::error::{Error, Result};
 use crate::meta;
-use anyhow::Result;
 
 pub trait BitPattern: std::fmt::Display + super::Code {
     /// Returns bit pattern and bit pattern length.
@@ -30,7 +30,8 @@ impl super::Code for P0 {
         let meta =
             Box::new(meta::common::bit_patterns::P0Meta::new()) as Box<dyn meta::common::Meta>;
         let parameters_c_code = Self::parameters_c_code()?;
-        Ok(meta.c_code(&parameters_c_code)?)
+        meta.c_code(&parameters_c_code)
+            .map_err(Error::specification)
     }
 
     fn parameters_c_code() -> Result<Vec<String>> {
@@ -60,7 +61,8 @@ impl super::Code for P1 {
         let meta =
             Box::new(meta::common::bit_patterns::P1Meta::new()) as Box<dyn meta::common::Meta>;
         let parameters_c_code = Self::parameters_c_code()?;
-        Ok(meta.c_code(&parameters_c_code)?)
+        meta.c_code(&parameters_c_code)
+            .map_err(Error::specification)
     }
 
     fn parameters_c_code() -> Result<Vec<String>> {
@@ -90,7 +92,8 @@ impl super::Code for P10 {
         let meta =
             Box::new(meta::common::bit_patterns::P10Meta::new()) as Box<dyn meta::common::Meta>;
         let parameters_c_code = Self::parameters_c_code()?;
-        Ok(meta.c_code(&parameters_c_code)?)
+        meta.c_code(&parameters_c_code)
+            .map_err(Error::specification)
     }
 
     fn parameters_c_code() -> Result<Vec<String>> {
@@ -121,7 +124,8 @@ impl super::Code for P01 {
         let meta =
             Box::new(meta::common::bit_patterns::P01Meta::new()) as Box<dyn meta::common::Meta>;
         let parameters_c_code = Self::parameters_c_code()?;
-        Ok(meta.c_code(&parameters_c_code)?)
+        meta.c_code(&parameters_c_code)
+            .map_err(Error::specification)
     }
 
     fn parameters_c_code() -> Result<Vec<String>> {
@@ -0,0 +1,117 @@
+/// Structures whose bits can be read a word at a time.
+pub trait WordGet {
+    /// The number of bits in the structure.
+    fn bit_len(&self) -> usize;
+
+    /// Returns the `len` bits starting at position `index`, the bit at `index` being the least significant.
+    fn get_word(&self, index: usize, len: u8) -> u64;
+}
+
+const WORD_LEN: usize = 64;
+
+fn read_word<T: WordGet>(structure: &T, index: usize, len: usize, ones: bool) -> u64 {
+    let word = structure.get_word(index, len as u8);
+    if ones {
+        word
+    } else {
+        let mask = if len == WORD_LEN {
+            u64::MAX
+        } else {
+            (1 << len) - 1
+        };
+        !word & mask
+    }
+}
+
+fn scan_forward<T: WordGet>(structure: &T, position: usize, ones: bool) -> Option<usize> {
+    let len = structure.bit_len();
+    let mut index = position;
+    while index < len {
+        let word_len = std::cmp::min(WORD_LEN, len - index);
+        let word = read_word(structure, index, word_len, ones);
+        if word != 0 {
+            return Some(index + word.trailing_zeros() as usize);
+        }
+        index += word_len;
+    }
+    None
+}
+
+fn scan_backward<T: WordGet>(structure: &T, position: usize, ones: bool) -> Option<usize> {
+    let len = structure.bit_len();
+    if len == 0 {
+        return None;
+    }
+    let mut end = std::cmp::min(position, len - 1) + 1;
+    while end > 0 {
+        let word_len = std::cmp::min(WORD_LEN, end);
+        let start = end - word_len;
+        let word = read_word(structure, start, word_len, ones);
+        if word != 0 {
+            return Some(start + WORD_LEN - 1 - word.leading_zeros() as usize);
+        }
+        end = start;
+    }
+    None
+}
+
+/// Returns the position of the first set bit at or after `position`.
+pub fn next_one<T: WordGet>(structure: &T, position: usize) -> Option<usize> {
+    scan_forward(structure, position, true)
+}
+
+/// Returns the position of the last set bit at or before `position`.
+pub fn prev_one<T: WordGet>(structure: &T, position: usize) -> Option<usize> {
+    scan_backward(structure, position, true)
+}
+
+/// Returns the position of the first unset bit at or after `position`.
+pub fn next_zero<T: WordGet>(structure: &T, position: usize) -> Option<usize> {
+    scan_forward(structure, position, false)
+}
+
+/// Returns the position of the last unset bit at or before `position`.
+pub fn prev_zero<T: WordGet>(structure: &T, position: usize) -> Option<usize> {
+    scan_backward(structure, position, false)
+}
+
+/// An iterator over the positions of set bits, reading one word per step.
+pub struct Ones<'a, T: WordGet> {
+    structure: &'a T,
+    len: usize,
+    index: usize,
+    word: u64,
+    word_start: usize,
+}
+
+impl<'a, T: WordGet> Ones<'a, T> {
+    pub fn new(structure: &'a T) -> Self {
+        Self {
+            structure,
+            len: structure.bit_len(),
+            index: 0,
+            word: 0,
+            word_start: 0,
+        }
+    }
+}
+
+impl<'a, T: WordGet> Iterator for Ones<'a, T> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.word == 0 {
+            if self.index >= self.len {
+                return None;
+            }
+            let word_len = std::cmp::min(WORD_LEN, self.len - self.index);
+            self.word = self.structure.get_word(self.index, word_len as u8);
+            self.word_start = self.index;
+            self.index += word_len;
+        }
+        let position = self.word_start + self.word.trailing_zeros() as usize;
+        // Clear the lowest set bit.
+        self.word &= self.word - 1;
+        Some(position)
+    }
+}
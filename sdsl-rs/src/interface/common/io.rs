@@ -1,5 +1,5 @@
 use crate::backend::sdsl_c;
-use anyhow::{format_err, Result};
+use crate::error::{Error, Result};
 
 use crate::interface::common;
 
@@ -8,20 +8,38 @@ pub struct Interface {
     store_to_file: extern "C" fn(common::VoidPtr, *const std::os::raw::c_char) -> bool,
     store_int_vector_to_file:
         extern "C" fn(common::VoidPtr, *const std::os::raw::c_char, bool) -> bool,
-    pub load_from_file: extern "C" fn(common::VoidPtr, *const std::os::raw::c_char) -> bool,
+    load_from_file: extern "C" fn(common::VoidPtr, *const std::os::raw::c_char) -> bool,
+    serialize_to_writer: extern "C" fn(common::VoidPtr, *mut libc::c_void, WriteCallback) -> u64,
+    load_from_reader: extern "C" fn(common::VoidPtr, *mut libc::c_void, ReadCallback) -> bool,
+    serialize_to_bytes: extern "C" fn(common::VoidPtr, *mut usize) -> *mut u8,
+    free_bytes: extern "C" fn(*mut u8),
+    load_from_bytes: extern "C" fn(common::VoidPtr, *const u8, usize) -> bool,
+    size_in_bytes: extern "C" fn(common::VoidPtr) -> u64,
+    write_structure_json: extern "C" fn(common::VoidPtr) -> *mut std::os::raw::c_char,
+    write_structure_html: extern "C" fn(common::VoidPtr) -> *mut std::os::raw::c_char,
+    free_structure_string: extern "C" fn(*mut std::os::raw::c_char),
 
-    _lib: std::sync::Arc<sharedlib::Lib>,
+    _lib: std::sync::Arc<sdsl_c::Library>,
 }
 
 impl Interface {
     pub fn new(id: &str) -> Result<Self> {
-        let lib = sdsl_c::LIB.clone();
+        let lib = sdsl_c::lib()?;
         let builder = sdsl_c::FunctionBuilder::new(None, id, lib.clone());
 
         Ok(Self {
-            store_to_file: builder.get("store_to_file")?,
-            store_int_vector_to_file: builder.get("store_int_vector_to_file")?,
-            load_from_file: builder.get("load_from_file")?,
+            store_to_file: builder.get("try_store_to_file")?,
+            store_int_vector_to_file: builder.get("try_store_int_vector_to_file")?,
+            load_from_file: builder.get("try_load_from_file")?,
+            serialize_to_writer: builder.get("serialize_to_writer")?,
+            load_from_reader: builder.get("load_from_reader")?,
+            serialize_to_bytes: builder.get("serialize_to_bytes")?,
+            free_bytes: builder.get("free_bytes")?,
+            load_from_bytes: builder.get("load_from_bytes")?,
+            size_in_bytes: builder.get("size_in_bytes")?,
+            write_structure_json: builder.get("write_structure_json")?,
+            write_structure_html: builder.get("write_structure_html")?,
+            free_structure_string: builder.get("free_structure_string")?,
 
             _lib: lib.clone(),
         })
@@ -33,53 +51,477 @@ pub trait IO {
 }
 
 /// Write structure to file.
-pub fn store_to_file<T: IO + common::Ptr>(
-    structure: &T,
-    path: &std::path::PathBuf,
-) -> Result<bool> {
-    let path = path
-        .to_str()
-        .ok_or(format_err!("Failed to convert PathBuf into str"))?;
-    let path = std::ffi::CString::new(path)?;
-    Ok((structure.io().store_to_file)(
-        *structure.ptr(),
-        path.as_ptr(),
-    ))
+///
+/// Fails if the file can not be created or written.
+pub fn store_to_file<T: IO + common::Ptr>(structure: &T, path: &std::path::PathBuf) -> Result<()> {
+    let c_path = c_path(path)?;
+    let stored = (structure.io().store_to_file)(*structure.ptr(), c_path.as_ptr());
+    sdsl_c::check_last_error()?;
+    check_stored(stored, path)
 }
 
 /// Write int vector to file.
+///
+/// Fails if the file can not be created or written.
 pub fn store_int_vector_to_file<T: IO + common::Ptr>(
     structure: &T,
     path: &std::path::PathBuf,
     write_fixed_as_variable: bool,
-) -> Result<bool> {
-    let path = path
-        .to_str()
-        .ok_or(format_err!("Failed to convert PathBuf into str"))?;
-    let path = std::ffi::CString::new(path)?;
-    Ok((structure.io().store_int_vector_to_file)(
+) -> Result<()> {
+    let c_path = c_path(path)?;
+    let stored = (structure.io().store_int_vector_to_file)(
         *structure.ptr(),
-        path.as_ptr(),
+        c_path.as_ptr(),
         write_fixed_as_variable,
-    ))
+    );
+    sdsl_c::check_last_error()?;
+    check_stored(stored, path)
+}
+
+fn check_stored(stored: bool, path: &std::path::Path) -> Result<()> {
+    if !stored {
+        return Err(Error::io(
+            std::io::ErrorKind::Other,
+            format!("Failed to store structure to {}.", path.display()),
+        ));
+    }
+    Ok(())
 }
 
 /// Load structure from file.
+///
+/// Fails if the file is missing or unreadable, if it is truncated, or if it holds a different structure type or width.
 pub fn load_from_file<T: IO + common::Ptr>(
     structure: &mut T,
     path: &std::path::PathBuf,
-) -> Result<bool> {
-    let path = path
-        .to_str()
-        .ok_or(format_err!("Failed to convert PathBuf into str."))?;
-    let path = std::ffi::CString::new(path)?;
+) -> Result<()> {
+    let file_len = file_len(path)?;
+    let c_path = c_path(path)?;
+
+    let loaded = (structure.io().load_from_file)(*structure.ptr_mut(), c_path.as_ptr());
+    sdsl_c::check_last_error()?;
+    if !loaded {
+        return Err(Error::io(
+            std::io::ErrorKind::InvalidData,
+            format!(
+                "Failed to load structure from {}: file is truncated or corrupt.",
+                path.display()
+            ),
+        ));
+    }
+    check_loaded_len(structure, path, file_len)
+}
+
+/// Convert a path into a C string.
+pub(crate) fn c_path(path: &std::path::Path) -> Result<std::ffi::CString> {
+    let path = path.to_str().ok_or_else(|| {
+        Error::InvalidArgument(format!("Path {} is not valid UTF-8.", path.display()))
+    })?;
+    Ok(std::ffi::CString::new(path)?)
+}
+
+/// Returns the length in bytes of the file at `path`, failing with a descriptive error if it can not be read.
+pub(crate) fn file_len(path: &std::path::Path) -> Result<u64> {
+    let metadata = std::fs::metadata(path).map_err(|error| {
+        let message = format!("Failed to open {}: {}", path.display(), error);
+        Error::io_caused_by(error, message)
+    })?;
+    if !metadata.is_file() {
+        return Err(Error::io(
+            std::io::ErrorKind::InvalidInput,
+            format!("Failed to open {}: not a file.", path.display()),
+        ));
+    }
+    Ok(metadata.len())
+}
+
+/// Check that loading a structure from a file of `file_len` bytes consumed the whole file.
+///
+/// SDSL reads a fixed layout, so a file holding a different structure type or width generally leaves bytes unread
+/// or is reported as truncated.
+pub(crate) fn check_loaded_len<T: IO + common::Ptr>(
+    structure: &T,
+    path: &std::path::Path,
+    file_len: u64,
+) -> Result<()> {
+    let loaded_len = (structure.io().size_in_bytes)(*structure.ptr());
+    if loaded_len != file_len {
+        return Err(Error::io(
+            std::io::ErrorKind::InvalidData,
+            format!(
+                "Failed to load structure from {}: read {} of {} bytes, file holds a different structure type or width.",
+                path.display(),
+                loaded_len,
+                file_len
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Called by the C stream adapter to write `len` bytes. Returns false on failure.
+type WriteCallback = extern "C" fn(*mut libc::c_void, *const u8, usize) -> bool;
+
+/// Called by the C stream adapter to read up to `len` bytes. Returns the number of bytes read, 0 at end of stream or on failure.
+type ReadCallback = extern "C" fn(*mut libc::c_void, *mut u8, usize) -> usize;
+
+struct StreamContext<'a, S: ?Sized> {
+    stream: &'a mut S,
+    error: Option<std::io::Error>,
+    panicked: bool,
+}
+
+impl<'a, S: ?Sized> StreamContext<'a, S> {
+    fn new(stream: &'a mut S) -> Self {
+        Self {
+            stream,
+            error: None,
+            panicked: false,
+        }
+    }
+
+    /// Returns the error of the stream, if any.
+    fn take_error(&mut self, action: &str) -> Option<Error> {
+        if self.panicked {
+            return Some(Error::io(
+                std::io::ErrorKind::Other,
+                format!("Panic while {} structure.", action),
+            ));
+        }
+        self.error.take().map(Error::from)
+    }
+}
+
+extern "C" fn write_callback<W: std::io::Write>(
+    context: *mut libc::c_void,
+    data: *const u8,
+    len: usize,
+) -> bool {
+    let context = unsafe { &mut *(context as *mut StreamContext<W>) };
+    let data = unsafe { std::slice::from_raw_parts(data, len) };
+    // Unwinding into C++ is undefined behaviour, report panics as write failures.
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        context.stream.write_all(data)
+    }));
+    match result {
+        Ok(Ok(())) => true,
+        Ok(Err(error)) => {
+            context.error = Some(error);
+            false
+        }
+        Err(_) => {
+            context.panicked = true;
+            false
+        }
+    }
+}
+
+extern "C" fn read_callback<R: std::io::Read>(
+    context: *mut libc::c_void,
+    data: *mut u8,
+    len: usize,
+) -> usize {
+    let context = unsafe { &mut *(context as *mut StreamContext<R>) };
+    let data = unsafe { std::slice::from_raw_parts_mut(data, len) };
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| loop {
+        match context.stream.read(data) {
+            Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+            result => return result,
+        }
+    }));
+    match result {
+        Ok(Ok(read)) => read,
+        Ok(Err(error)) => {
+            context.error = Some(error);
+            0
+        }
+        Err(_) => {
+            context.panicked = true;
+            0
+        }
+    }
+}
 
-    Ok((structure.io().load_from_file)(
+/// Write structure to a stream in SDSL's serialization format.
+///
+/// Returns the number of bytes written.
+pub fn serialize<T: IO + common::Ptr, W: std::io::Write>(
+    structure: &T,
+    writer: &mut W,
+) -> Result<usize> {
+    let mut context = StreamContext::new(writer);
+    let written = (structure.io().serialize_to_writer)(
         *structure.ptr(),
-        path.as_ptr(),
-    ))
+        &mut context as *mut StreamContext<W> as *mut libc::c_void,
+        write_callback::<W>,
+    );
+    // A failing stream also surfaces as a C++ exception, report the stream error in that case.
+    let cpp_result = sdsl_c::check_last_error();
+    if let Some(error) = context.take_error("writing") {
+        return Err(error);
+    }
+    cpp_result?;
+    context.stream.flush()?;
+    Ok(written as usize)
+}
+
+/// Load structure from a stream in SDSL's serialization format.
+pub fn load<T: IO + common::Ptr, R: std::io::Read>(
+    structure: &mut T,
+    reader: &mut R,
+) -> Result<()> {
+    let mut context = StreamContext::new(reader);
+    let loaded = (structure.io().load_from_reader)(
+        *structure.ptr_mut(),
+        &mut context as *mut StreamContext<R> as *mut libc::c_void,
+        read_callback::<R>,
+    );
+    let cpp_result = sdsl_c::check_last_error();
+    if let Some(error) = context.take_error("reading") {
+        return Err(error);
+    }
+    cpp_result?;
+    if !loaded {
+        return Err(Error::io(
+            std::io::ErrorKind::InvalidData,
+            "Failed to load structure from stream.".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the structure in SDSL's serialization format.
+///
+/// Fails if SDSL can not allocate the buffer.
+pub fn to_bytes<T: IO + common::Ptr>(structure: &T) -> Result<Vec<u8>> {
+    let io = structure.io();
+    let mut len = 0;
+    let data = (io.serialize_to_bytes)(*structure.ptr(), &mut len);
+    sdsl_c::check_last_error()?;
+    let bytes = common::array_from_c_array(data, len).to_vec();
+    (io.free_bytes)(data);
+    Ok(bytes)
+}
+
+/// Load structure from a buffer in SDSL's serialization format.
+pub fn load_from_bytes<T: IO + common::Ptr>(structure: &mut T, bytes: &[u8]) -> Result<()> {
+    let loaded =
+        (structure.io().load_from_bytes)(*structure.ptr_mut(), bytes.as_ptr(), bytes.len());
+    sdsl_c::check_last_error()?;
+    if !loaded {
+        return Err(Error::io(
+            std::io::ErrorKind::InvalidData,
+            "Failed to load structure from bytes.".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Deserialize a byte buffer written by `serializer.serialize_bytes`.
+///
+/// Accepts byte sequences as well, since self-describing formats such as JSON encode bytes as lists.
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize_bytes<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Vec<u8>, D::Error> {
+    struct BytesVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for BytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("SDSL serialized bytes")
+        }
+
+        fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> std::result::Result<Vec<u8>, E> {
+            Ok(bytes.to_vec())
+        }
+
+        fn visit_byte_buf<E: serde::de::Error>(
+            self,
+            bytes: Vec<u8>,
+        ) -> std::result::Result<Vec<u8>, E> {
+            Ok(bytes)
+        }
+
+        fn visit_seq<A: serde::de::SeqAccess<'de>>(
+            self,
+            mut seq: A,
+        ) -> std::result::Result<Vec<u8>, A::Error> {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(byte) = seq.next_element()? {
+                bytes.push(byte);
+            }
+            Ok(bytes)
+        }
+    }
+
+    deserializer.deserialize_byte_buf(BytesVisitor)
+}
+
+/// Deserializes a structure which supports a bit vector, such as a rank or select support, with serde's
+/// `DeserializeSeed`.
+///
+/// The serialized structure does not contain the bit vector, the seed provides it instead.
+#[cfg(feature = "serde")]
+pub struct SupportSeed<'a, T> {
+    bit_vector: &'a crate::interface::bit_vectors::bit_vector::BitVector,
+    from_bytes: fn(&[u8], &'a crate::interface::bit_vectors::bit_vector::BitVector) -> Result<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<'a, T> SupportSeed<'a, T> {
+    pub(crate) fn new(
+        bit_vector: &'a crate::interface::bit_vectors::bit_vector::BitVector,
+        from_bytes: fn(
+            &[u8],
+            &'a crate::interface::bit_vectors::bit_vector::BitVector,
+        ) -> Result<T>,
+    ) -> Self {
+        Self {
+            bit_vector,
+            from_bytes,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, 'a, T> serde::de::DeserializeSeed<'de> for SupportSeed<'a, T> {
+    type Value = T;
+
+    fn deserialize<D: serde::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> std::result::Result<T, D::Error> {
+        let bytes = deserialize_bytes(deserializer)?;
+        (self.from_bytes)(&bytes, self.bit_vector).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Memory usage of a structure.
+pub trait SpaceUsage {
+    /// Returns the number of bytes required to serialize the structure.
+    fn size_in_bytes(&self) -> u64;
+
+    /// Returns the number of mebibytes required to serialize the structure.
+    fn size_in_mega_bytes(&self) -> f64 {
+        self.size_in_bytes() as f64 / (1024.0 * 1024.0)
+    }
+
+    /// Returns a breakdown of the space used by the structure and its components.
+    fn structure_tree(&self) -> Result<StructureTree>;
+}
+
+impl<T: IO + common::Ptr> SpaceUsage for T {
+    fn size_in_bytes(&self) -> u64 {
+        (self.io().size_in_bytes)(*self.ptr())
+    }
+
+    fn structure_tree(&self) -> Result<StructureTree> {
+        let io = self.io();
+        let c_string = (io.write_structure_json)(*self.ptr());
+        sdsl_c::check_last_error()?;
+        let json = string_from_c(c_string, io);
+        StructureTree::from_json(&json)
+    }
+}
+
+/// A node of the space usage breakdown of a structure, as reported by SDSL's `structure_tree_node`.
+///
+/// Deserializable from the JSON report written by `write_structure` with `Format::Json`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(crate = "serde")]
+pub struct StructureTree {
+    /// Member name of the component within its parent.
+    pub name: String,
+    /// SDSL class name of the component.
+    pub class_name: String,
+    /// Number of bytes used by the component, including its children.
+    #[serde(rename = "size", deserialize_with = "deserialize_size")]
+    pub size_in_bytes: u64,
+    #[serde(default)]
+    pub children: Vec<StructureTree>,
+}
+
+impl StructureTree {
+    /// Parse a structure tree from a JSON report.
+    /// # Arguments
+    /// * `json` - JSON report as written by `write_structure` with `Format::Json`.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let invalid_json =
+            |error| Error::InvalidArgument(format!("Invalid structure tree JSON: {}", error));
+        let value: serde_json::Value = serde_json::from_str(json).map_err(invalid_json)?;
+        // The root may be wrapped in a single element list.
+        let value = match value {
+            serde_json::Value::Array(values) => values.into_iter().next().ok_or_else(|| {
+                Error::InvalidArgument("Structure tree JSON contains no nodes.".to_string())
+            })?,
+            value => value,
+        };
+        serde_json::from_value(value).map_err(invalid_json)
+    }
+}
+
+/// SDSL reports sizes as strings, accept numbers as well.
+fn deserialize_size<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<u64, D::Error> {
+    #[derive(serde::Deserialize)]
+    #[serde(crate = "serde", untagged)]
+    enum Size {
+        String(String),
+        Number(u64),
+    }
+
+    match serde::Deserialize::deserialize(deserializer)? {
+        Size::String(size) => size.parse().map_err(serde::de::Error::custom),
+        Size::Number(size) => Ok(size),
+    }
+}
+
+/// Report format for `write_structure`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Html,
+}
+
+/// Write a report of the space used by the structure and its components.
+/// # Arguments
+/// * `structure` - Structure to report on.
+/// * `format` - Report format.
+/// * `writer` - Destination of the report.
+pub fn write_structure<T: IO + common::Ptr, W: std::io::Write>(
+    structure: &T,
+    format: Format,
+    writer: &mut W,
+) -> Result<()> {
+    let io = structure.io();
+    let c_string = match format {
+        Format::Json => (io.write_structure_json)(*structure.ptr()),
+        Format::Html => (io.write_structure_html)(*structure.ptr()),
+    };
+    sdsl_c::check_last_error()?;
+    let report = string_from_c(c_string, io);
+    writer.write_all(report.as_bytes())?;
+    Ok(())
+}
+
+/// Copies a string allocated by the C interface and releases the original.
+fn string_from_c(c_string: *mut std::os::raw::c_char, io: &Interface) -> String {
+    let string = unsafe { std::ffi::CStr::from_ptr(c_string) }
+        .to_string_lossy()
+        .into_owned();
+    (io.free_structure_string)(c_string);
+    string
 }
 
 pub mod crate_export {
-    pub use super::{load_from_file, store_int_vector_to_file, store_to_file};
+    pub use super::{
+        load, load_from_bytes, load_from_file, serialize, store_int_vector_to_file, store_to_file,
+        to_bytes, write_structure, Format, SpaceUsage, StructureTree,
+    };
+
+    #[cfg(feature = "serde")]
+    pub use super::SupportSeed;
 }
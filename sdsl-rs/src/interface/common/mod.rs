@@ -1,5 +1,5 @@
-use anyhow::Result;
 pub mod bit_patterns;
+pub mod bit_scan;
 pub mod io;
 pub mod util;
 
@@ -7,35 +7,108 @@ pub type VoidPtr = *const libc::c_void;
 
 pub trait Ptr {
     fn ptr(&self) -> &VoidPtr;
+
+    /// Returns the pointer for a call which may modify the structure, such as loading or clearing it.
+    fn ptr_mut(&mut self) -> &VoidPtr {
+        self.ptr()
+    }
+}
+
+/// The cached length of a structure, so that checked accessors do not need an extra FFI call.
+///
+/// Must be invalidated by every call which may change the length.
+#[derive(Default)]
+pub struct LenCache(std::cell::Cell<Option<usize>>);
+
+impl LenCache {
+    /// Returns the cached length, calling `len` if it is not cached.
+    pub fn get<F: FnOnce() -> usize>(&self, len: F) -> usize {
+        match self.0.get() {
+            Some(len) => len,
+            None => {
+                let len = len();
+                self.0.set(Some(len));
+                len
+            }
+        }
+    }
+
+    pub fn invalidate(&self) {
+        self.0.set(None);
+    }
 }
 
 pub trait Id: Code {
-    fn id() -> Result<String>;
+    fn id() -> crate::error::Result<String>;
 }
 
 pub trait Code {
-    fn c_code() -> Result<String>;
-    fn parameters_c_code() -> Result<Vec<String>>;
+    fn c_code() -> crate::error::Result<String>;
+    fn parameters_c_code() -> crate::error::Result<Vec<String>>;
 }
 
 pub trait IterGet<Value> {
     fn iter_get(&self, index: usize) -> Value;
+
+    /// Replaces the contents of `values` with `len` consecutive values starting at `index`.
+    ///
+    /// Structures which support bulk access should override this method so that
+    /// iterators fetch a chunk of values per FFI call.
+    fn iter_get_range(&self, index: usize, len: usize, values: &mut Vec<Value>) {
+        values.clear();
+        values.extend((index..index + len).map(|i| self.iter_get(i)));
+    }
+}
+
+/// Number of values fetched per FFI call by vector iterators.
+const ITER_CHUNK_SIZE: usize = 1024;
+
+/// Returns the next value of a vector iterator, refilling `chunk` from `vector` once it is empty.
+///
+/// The chunk holds the fetched values in reverse order so that they can be popped, which keeps its buffer for the
+/// next refill.
+fn next_chunk_value<Value, Iterable: IterGet<Value>>(
+    vector: &Iterable,
+    len: usize,
+    index: &mut usize,
+    chunk: &mut Vec<Value>,
+) -> Option<Value> {
+    if chunk.is_empty() {
+        if *index >= len {
+            return None;
+        }
+        let chunk_len = std::cmp::min(ITER_CHUNK_SIZE, len - *index);
+        vector.iter_get_range(*index, chunk_len, chunk);
+        chunk.reverse();
+        *index += chunk_len;
+    }
+    chunk.pop()
 }
 
 pub struct VectorIterator<'a, Value, Iterable: IterGet<Value>> {
-    _t: Option<Value>,
     vector: &'a Iterable,
     len: usize,
     index: usize,
+    chunk: Vec<Value>,
 }
 
 impl<'a, Value, Iterable: IterGet<Value>> VectorIterator<'a, Value, Iterable> {
     pub fn new(vector: &'a Iterable, len: usize) -> Self {
         Self {
-            _t: None,
             vector,
             len,
             index: 0,
+            chunk: Vec::new(),
+        }
+    }
+
+    /// Iterate over the values with indexes in `range`.
+    pub fn with_range(vector: &'a Iterable, range: std::ops::Range<usize>) -> Self {
+        Self {
+            vector,
+            len: range.end,
+            index: range.start,
+            chunk: Vec::new(),
         }
     }
 }
@@ -44,30 +117,24 @@ impl<'a, Value, Iterable: IterGet<Value>> Iterator for VectorIterator<'a, Value,
     type Item = Value;
 
     fn next(&mut self) -> Option<Value> {
-        let result = if self.index < self.len {
-            Some(self.vector.iter_get(self.index))
-        } else {
-            None
-        };
-        self.index = self.index + 1;
-        result
+        next_chunk_value(self.vector, self.len, &mut self.index, &mut self.chunk)
     }
 }
 
 pub struct VectorIntoIterator<Value, Iterable: IterGet<Value>> {
-    _t: Option<Value>,
     vector: Iterable,
     len: usize,
     index: usize,
+    chunk: Vec<Value>,
 }
 
 impl<Value, Iterable: IterGet<Value>> VectorIntoIterator<Value, Iterable> {
     pub fn new(vector: Iterable, len: usize) -> Self {
         Self {
-            _t: None,
             vector,
             len,
             index: 0,
+            chunk: Vec::new(),
         }
     }
 }
@@ -76,13 +143,70 @@ impl<Value, Iterable: IterGet<Value>> Iterator for VectorIntoIterator<Value, Ite
     type Item = Value;
 
     fn next(&mut self) -> Option<Value> {
-        let result = if self.index < self.len {
-            Some(self.vector.iter_get(self.index))
-        } else {
-            None
-        };
-        self.index += 1;
-        result
+        next_chunk_value(&self.vector, self.len, &mut self.index, &mut self.chunk)
+    }
+}
+
+/// A read-only view of consecutive elements of a vector.
+pub struct VectorSlice<'a, Value, Iterable: IterGet<Value>> {
+    vector: &'a Iterable,
+    start: usize,
+    len: usize,
+    _value: std::marker::PhantomData<Value>,
+}
+
+impl<'a, Value, Iterable: IterGet<Value>> VectorSlice<'a, Value, Iterable> {
+    /// Construct a view of the elements of `vector` with indexes in `range`.
+    ///
+    /// Panics if `range` is not within the first `vector_len` elements of `vector`.
+    pub fn new(vector: &'a Iterable, vector_len: usize, range: std::ops::Range<usize>) -> Self {
+        assert!(
+            range.start <= range.end && range.end <= vector_len,
+            "Range {:?} out of bounds for length {}.",
+            range,
+            vector_len
+        );
+        Self {
+            vector,
+            start: range.start,
+            len: range.end - range.start,
+            _value: std::marker::PhantomData,
+        }
+    }
+
+    /// The number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the view is empty, otherwise returns false.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the i-th element of the view.
+    ///
+    /// Panics if `index` is out of bounds.
+    /// # Arguments
+    /// * `index` - An index in range $ [0, \mathrm{len}()) $.
+    pub fn get(&self, index: usize) -> Value {
+        if let Err(error) = crate::error::check_index(index, self.len) {
+            panic!("{}", error);
+        }
+        self.vector.iter_get(self.start + index)
+    }
+
+    /// Copy the elements of the view into a `Vec`.
+    pub fn to_vec(&self) -> Vec<Value> {
+        let mut values = Vec::with_capacity(self.len);
+        self.vector
+            .iter_get_range(self.start, self.len, &mut values);
+        values
+    }
+
+    /// Returns an iterator over the elements of the view.
+    pub fn iter(&self) -> VectorIterator<'a, Value, Iterable> {
+        VectorIterator::with_range(self.vector, self.start..self.start + self.len)
     }
 }
 
@@ -1,5 +1,5 @@
 use crate::backend::sdsl_c;
-use anyhow::Result;
+use crate::error::Result;
 
 use crate::interface::common;
 
@@ -11,13 +11,23 @@ pub struct Interface {
     util_mod: extern "C" fn(common::VoidPtr, u64),
     bit_compress: extern "C" fn(common::VoidPtr),
     expand_width: extern "C" fn(common::VoidPtr, u8),
+    set_random_bits_with_seed: extern "C" fn(common::VoidPtr, u64),
+    cnt_one_bits: extern "C" fn(common::VoidPtr) -> u64,
+    cnt_onezero_bits: extern "C" fn(common::VoidPtr) -> u64,
+    cnt_zeroone_bits: extern "C" fn(common::VoidPtr) -> u64,
+    clear: extern "C" fn(common::VoidPtr),
+    swap: extern "C" fn(common::VoidPtr, common::VoidPtr),
+    to_string: extern "C" fn(common::VoidPtr) -> *mut std::os::raw::c_char,
+    to_latex_string: extern "C" fn(common::VoidPtr) -> *mut std::os::raw::c_char,
+    class_name: extern "C" fn(common::VoidPtr) -> *mut std::os::raw::c_char,
+    free_string: extern "C" fn(*mut std::os::raw::c_char),
 
-    _lib: std::sync::Arc<sharedlib::Lib>,
+    _lib: std::sync::Arc<sdsl_c::Library>,
 }
 
 impl Interface {
     pub fn new(id: &str) -> Result<Self> {
-        let lib = sdsl_c::LIB.clone();
+        let lib = sdsl_c::lib()?;
         let builder = sdsl_c::FunctionBuilder::new(None, id, lib.clone());
 
         Ok(Self {
@@ -26,7 +36,17 @@ impl Interface {
             set_random_bits: builder.get("set_random_bits")?,
             util_mod: builder.get("mod")?,
             bit_compress: builder.get("bit_compress")?,
-            expand_width: builder.get("expand_width")?,
+            expand_width: builder.get("try_expand_width")?,
+            set_random_bits_with_seed: builder.get("set_random_bits_with_seed")?,
+            cnt_one_bits: builder.get("cnt_one_bits")?,
+            cnt_onezero_bits: builder.get("cnt_onezero_bits")?,
+            cnt_zeroone_bits: builder.get("cnt_zeroone_bits")?,
+            clear: builder.get("clear")?,
+            swap: builder.get("swap")?,
+            to_string: builder.get("to_string")?,
+            to_latex_string: builder.get("to_latex_string")?,
+            class_name: builder.get("class_name")?,
+            free_string: builder.get("free_string")?,
 
             _lib: lib.clone(),
         })
@@ -38,31 +58,94 @@ pub trait Util {
 }
 
 pub fn set_to_value<T: Util + common::Ptr>(structure: &mut T, value: u64) {
-    (structure.util().set_to_value)(*structure.ptr(), value)
+    (structure.util().set_to_value)(*structure.ptr_mut(), value)
 }
 
 pub fn set_to_id<T: Util + common::Ptr>(structure: &mut T) {
-    (structure.util().set_to_id)(*structure.ptr())
+    (structure.util().set_to_id)(*structure.ptr_mut())
 }
 
 pub fn set_random_bits<T: Util + common::Ptr>(structure: &mut T) {
-    (structure.util().set_random_bits)(*structure.ptr())
+    (structure.util().set_random_bits)(*structure.ptr_mut())
 }
 
 pub fn modulus<T: Util + common::Ptr>(structure: &mut T, value: u64) {
-    (structure.util().util_mod)(*structure.ptr(), value)
+    (structure.util().util_mod)(*structure.ptr_mut(), value)
 }
 
 pub fn bit_compress<T: Util + common::Ptr>(structure: &mut T) {
-    (structure.util().bit_compress)(*structure.ptr())
+    (structure.util().bit_compress)(*structure.ptr_mut())
 }
 
-pub fn expand_width<T: Util + common::Ptr>(structure: &mut T, new_width: u8) {
-    (structure.util().expand_width)(*structure.ptr(), new_width)
+/// Fails if SDSL can not allocate the wider storage.
+pub fn expand_width<T: Util + common::Ptr>(structure: &mut T, new_width: u8) -> Result<()> {
+    (structure.util().expand_width)(*structure.ptr_mut(), new_width);
+    sdsl_c::check_last_error()
+}
+
+/// Sets all bits of the structure to random bits generated from `seed`.
+///
+/// The same seed always produces the same bits.
+pub fn set_random_bits_with_seed<T: Util + common::Ptr>(structure: &mut T, seed: u64) {
+    (structure.util().set_random_bits_with_seed)(*structure.ptr_mut(), seed)
+}
+
+/// Returns the number of set bits in the structure.
+pub fn cnt_one_bits<T: Util + common::Ptr>(structure: &T) -> u64 {
+    (structure.util().cnt_one_bits)(*structure.ptr())
+}
+
+/// Returns the number of occurrences of bit pattern `10` in the structure.
+pub fn cnt_onezero_bits<T: Util + common::Ptr>(structure: &T) -> u64 {
+    (structure.util().cnt_onezero_bits)(*structure.ptr())
+}
+
+/// Returns the number of occurrences of bit pattern `01` in the structure.
+pub fn cnt_zeroone_bits<T: Util + common::Ptr>(structure: &T) -> u64 {
+    (structure.util().cnt_zeroone_bits)(*structure.ptr())
+}
+
+/// Clears the structure and releases its memory.
+pub fn clear<T: Util + common::Ptr>(structure: &mut T) {
+    (structure.util().clear)(*structure.ptr_mut())
+}
+
+/// Swaps the contents of two structures of the same type.
+pub fn swap<T: Util + common::Ptr>(a: &mut T, b: &mut T) {
+    (a.util().swap)(*a.ptr_mut(), *b.ptr_mut())
+}
+
+/// Returns the string representation of the structure.
+pub fn to_string<T: Util + common::Ptr>(structure: &T) -> String {
+    let util = structure.util();
+    string_from_c((util.to_string)(*structure.ptr()), util)
+}
+
+/// Returns the LaTeX representation of the structure.
+pub fn to_latex_string<T: Util + common::Ptr>(structure: &T) -> String {
+    let util = structure.util();
+    string_from_c((util.to_latex_string)(*structure.ptr()), util)
+}
+
+/// Returns the demangled C++ class name of the structure.
+pub fn class_name<T: Util + common::Ptr>(structure: &T) -> String {
+    let util = structure.util();
+    string_from_c((util.class_name)(*structure.ptr()), util)
+}
+
+/// Copies a string allocated by the C interface and releases the original.
+fn string_from_c(c_string: *mut std::os::raw::c_char, util: &Interface) -> String {
+    let string = unsafe { std::ffi::CStr::from_ptr(c_string) }
+        .to_string_lossy()
+        .into_owned();
+    (util.free_string)(c_string);
+    string
 }
 
 pub mod crate_export {
     pub use super::{
-        bit_compress, expand_width, modulus, set_random_bits, set_to_id, set_to_value,
+        bit_compress, class_name, clear, cnt_one_bits, cnt_onezero_bits, cnt_zeroone_bits,
+        expand_width, modulus, set_random_bits, set_random_bits_with_seed, set_to_id, set_to_value,
+        swap, to_latex_string, to_string,
     };
 }
@@ -1,6 +1,6 @@
 use crate::backend::sdsl_c;
+use crate::error::{Error, Result};
 use crate::meta;
-use anyhow::{format_err, Result};
 
 use crate::interface::common::{self, Code, Id};
 
@@ -14,7 +14,7 @@ use crate::interface::common::{self, Code, Id};
 /// # Example
 /// ```ignore
 /// let mut iv = sdsl::int_vectors::IntVector::<0>::new(5, 42, Some(64))?;
-/// iv.bit_resize(2 * iv.width() as usize);
+/// iv.bit_resize(2 * iv.width() as usize)?;
 ///
 /// let result: Vec<_> = iv.iter().collect();
 /// let expected = vec![42, 42];
@@ -24,6 +24,7 @@ use crate::interface::common::{self, Code, Id};
 /// For further examples see [here](https://github.com/sdsl-rs/sdsl-rs/blob/master/examples/src/int_vectors/int_vector.rs).
 pub struct IntVector<const WIDTH: u8> {
     ptr: common::VoidPtr,
+    len_cache: common::LenCache,
     interface: Interface,
 }
 
@@ -32,22 +33,77 @@ impl<const WIDTH: u8> IntVector<WIDTH> {
     /// # Arguments
     /// * `size` - Number of elements.
     /// * `default_value` - Default values for elements initialization.
-    /// * `width` - The width of each integer. Must be specified if `WIDTH == 0`.
+    /// * `width` - The width of each integer in range $ [1, 64] $. Must be specified if and only if `WIDTH == 0`.
     pub fn new(size: usize, default_value: usize, width: Option<u8>) -> Result<Self> {
-        assert!(
-            (WIDTH == 0 && width.is_some()) || (WIDTH != 0 && width.is_none()),
-            "Width argument must be specified iff WIDTH const generic value is 0."
-        );
-        let width = match width {
-            Some(width) => width,
-            None => WIDTH,
+        let width = match (WIDTH, width) {
+            (0, Some(width)) => width,
+            (_, None) if WIDTH != 0 => WIDTH,
+            _ => {
+                return Err(Error::InvalidArgument(
+                    "Width argument must be specified iff WIDTH const generic value is 0."
+                        .to_string(),
+                ))
+            }
         };
+        if width == 0 || width > 64 {
+            return Err(Error::InvalidArgument(format!(
+                "Integer width {} is not in range [1, 64].",
+                width
+            )));
+        }
 
         let id = Self::id()?;
         let interface = Interface::new(&id)?;
         let ptr = (interface.create)(size, default_value, width);
+        sdsl_c::check_last_error()?;
+
+        Ok(Self {
+            ptr,
+            len_cache: common::LenCache::default(),
+            interface,
+        })
+    }
+
+    /// Construct an integer vector from a slice using the minimal width which fits every value.
+    ///
+    /// The width is computed as in `sdsl::util::bit_compress` and is at least 1.
+    /// # Arguments
+    /// * `values` - Element values.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let iv = sdsl::int_vectors::IntVector::<0>::from_slice_with_width(&[1, 12, 3])?;
+    /// let result = iv.width();
+    /// let expected = 4;
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn from_slice_with_width(values: &[u64]) -> Result<Self> {
+        assert!(
+            WIDTH == 0,
+            "Generic const WIDTH must be zero when computing width from values."
+        );
+        let max_value = values.iter().copied().max().unwrap_or(0);
+        let width = std::cmp::max(64 - max_value.leading_zeros(), 1) as u8;
+        Self::from_values(values, Some(width))
+    }
 
-        Ok(Self { ptr, interface })
+    fn from_values(values: &[u64], width: Option<u8>) -> Result<Self> {
+        let mut int_vector = Self::new(values.len(), 0, width)?;
+        let width = int_vector.width();
+        for value in values {
+            check_width(*value, width)?;
+        }
+        int_vector.set_range(0, values);
+        Ok(int_vector)
+    }
+
+    /// Width used when constructing from Rust collections: 64 bits if `WIDTH` is 0.
+    fn collection_width() -> Option<u8> {
+        if WIDTH == 0 {
+            Some(64)
+        } else {
+            None
+        }
     }
 
     /// Load vector from file.
@@ -58,54 +114,293 @@ impl<const WIDTH: u8> IntVector<WIDTH> {
             WIDTH == 0,
             "Generic const WIDTH must be zero when loading from file."
         );
-        let int_vector = Self::new(1, 0, Some(64))?;
+        let mut int_vector = Self::new(1, 0, Some(64))?;
+        common::io::load_from_file(&mut int_vector, path)?;
+        Ok(int_vector)
+    }
+
+    /// Write the vector to a stream in SDSL's serialization format.
+    ///
+    /// Returns the number of bytes written.
+    /// # Arguments
+    /// * `writer` - Destination stream.
+    pub fn serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<usize> {
+        common::io::serialize(self, writer)
+    }
 
-        let path = path
-            .to_str()
-            .ok_or(format_err!("Failed to convert PathBuf into str."))?;
-        let path = std::ffi::CString::new(path)?;
+    /// Load a vector from a stream in SDSL's serialization format.
+    /// # Arguments
+    /// * `reader` - Source stream.
+    pub fn load<R: std::io::Read>(reader: &mut R) -> Result<Self> {
+        let mut vector = Self::new(1, 0, Self::collection_width())?;
+        common::io::load(&mut vector, reader)?;
+        Ok(vector)
+    }
 
-        (int_vector.interface.io.load_from_file)(int_vector.ptr, path.as_ptr());
-        Ok(int_vector)
+    /// Returns the vector in SDSL's serialization format.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        common::io::to_bytes(self)
+    }
+
+    /// Load a vector from a buffer in SDSL's serialization format.
+    /// # Arguments
+    /// * `bytes` - Serialized vector.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut vector = Self::new(1, 0, Self::collection_width())?;
+        common::io::load_from_bytes(&mut vector, bytes)?;
+        Ok(vector)
+    }
+
+    /// Returns a copy of the vector, or an error if SDSL fails to allocate it.
+    pub fn try_clone(&self) -> Result<Self> {
+        let ptr = (self.interface.clone)(self.ptr);
+        sdsl_c::check_last_error()?;
+        Ok(Self {
+            ptr,
+            len_cache: common::LenCache::default(),
+            interface: self.interface.clone(),
+        })
     }
 
     /// Get the i-th element of the vector.
+    ///
+    /// Panics if `index` is out of bounds, see `try_get` for a checked alternative.
     /// # Arguments
     /// * `index` - An index in range $ [0, \mathrm{len}()) $.
     pub fn get(&self, index: usize) -> usize {
+        self.try_get(index)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Get the i-th element of the vector or an error if `index` is out of bounds.
+    /// # Arguments
+    /// * `index` - An index in range $ [0, \mathrm{len}()) $.
+    pub fn try_get(&self, index: usize) -> Result<usize> {
+        crate::error::check_index(index, self.len())?;
+        Ok(unsafe { self.get_unchecked(index) })
+    }
+
+    /// Get the i-th element of the vector without bounds checking.
+    /// # Safety
+    /// `index` must be in range $ [0, \mathrm{len}()) $, otherwise memory outside of the vector is read.
+    /// # Arguments
+    /// * `index` - An index in range $ [0, \mathrm{len}()) $.
+    pub unsafe fn get_unchecked(&self, index: usize) -> usize {
         (self.interface.get)(self.ptr, index)
     }
 
     /// Set the i-th element of the vector.
+    ///
+    /// Panics if `index` is out of bounds.
     /// # Arguments
     /// * `index` - An index in range $ [0, \mathrm{len}()) $.
     /// * `value` - New element value.
     pub fn set(&mut self, index: usize, value: usize) {
+        if let Err(error) = crate::error::check_index(index, self.len()) {
+            panic!("{}", error);
+        }
+        unsafe { self.set_unchecked(index, value) }
+    }
+
+    /// Set the i-th element of the vector without bounds checking.
+    /// # Safety
+    /// `index` must be in range $ [0, \mathrm{len}()) $, otherwise memory outside of the vector is written.
+    /// # Arguments
+    /// * `index` - An index in range $ [0, \mathrm{len}()) $.
+    /// * `value` - New element value.
+    pub unsafe fn set_unchecked(&mut self, index: usize, value: usize) {
         (self.interface.set)(self.ptr, index, value)
     }
 
+    /// Returns a proxy for reading and writing the i-th element of the vector.
+    ///
+    /// Panics if `index` is out of bounds.
+    /// # Arguments
+    /// * `index` - An index in range $ [0, \mathrm{len}()) $.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut iv = sdsl::int_vector! {1, 12, 3};
+    /// iv.at_mut(1).set(42);
+    /// let result = iv.get(1);
+    /// let expected = 42;
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn at_mut(&mut self, index: usize) -> IntVectorElementMut<'_, WIDTH> {
+        if let Err(error) = crate::error::check_index(index, self.len()) {
+            panic!("{}", error);
+        }
+        IntVectorElementMut {
+            vector: self,
+            index,
+        }
+    }
+
+    /// Replace the i-th element of the vector with the result of `f` applied to its current value.
+    ///
+    /// Panics if `index` is out of bounds.
+    /// # Arguments
+    /// * `index` - An index in range $ [0, \mathrm{len}()) $.
+    /// * `f` - Function which maps the current element value to the new value.
+    pub fn update<F: FnOnce(usize) -> usize>(&mut self, index: usize, f: F) {
+        let mut element = self.at_mut(index);
+        let value = element.get();
+        element.set(f(value));
+    }
+
+    /// Copy consecutive elements of the vector into a buffer using a single FFI call.
+    /// # Arguments
+    /// * `start` - Index of the first element to copy.
+    /// * `values` - Buffer to fill. Elements $ [\mathrm{start}, \mathrm{start} + \mathrm{values.len}()) $ must be within the vector.
+    pub fn get_range(&self, start: usize, values: &mut [u64]) {
+        assert!(
+            start + values.len() <= self.len(),
+            "Range end {} exceeds vector length {}.",
+            start + values.len(),
+            self.len()
+        );
+        (self.interface.get_range)(self.ptr, start, values.len(), values.as_mut_ptr())
+    }
+
+    /// Overwrite consecutive elements of the vector using a single FFI call.
+    /// # Arguments
+    /// * `start` - Index of the first element to overwrite.
+    /// * `values` - New element values. Elements $ [\mathrm{start}, \mathrm{start} + \mathrm{values.len}()) $ must be within the vector.
+    pub fn set_range(&mut self, start: usize, values: &[u64]) {
+        assert!(
+            start + values.len() <= self.len(),
+            "Range end {} exceeds vector length {}.",
+            start + values.len(),
+            self.len()
+        );
+        (self.interface.set_range)(self.ptr, start, values.len(), values.as_ptr())
+    }
+
     /// Returns true if the vector is empty, otherwise returns false.
     pub fn is_empty(&self) -> bool {
         (self.interface.is_empty)(self.ptr)
     }
 
     /// Resize the vector in terms of elements.
+    ///
+    /// Returns an error if SDSL fails to allocate the storage.
     /// # Arguments
     /// * `size` - Target number of elements.
-    pub fn resize(&mut self, size: usize) {
-        (self.interface.resize)(self.ptr, size)
+    pub fn resize(&mut self, size: usize) -> Result<()> {
+        (self.interface.resize)(self.ptr, size);
+        self.len_cache.invalidate();
+        sdsl_c::check_last_error()
     }
 
     /// Resize the total vector in terms of bits.
+    ///
+    /// Returns an error if SDSL fails to allocate the storage.
     /// # Arguments
     /// * `size` - The size to resize the vector in terms of bits.
-    pub fn bit_resize(&mut self, size: usize) {
-        (self.interface.bit_resize)(self.ptr, size)
+    pub fn bit_resize(&mut self, size: usize) -> Result<()> {
+        (self.interface.bit_resize)(self.ptr, size);
+        self.len_cache.invalidate();
+        sdsl_c::check_last_error()
+    }
+
+    /// Shrink the vector to `len` elements, which releases storage and therefore can not fail.
+    fn shrink(&mut self, len: usize) {
+        self.resize(len)
+            .expect("Shrinking an int_vector does not allocate.");
+    }
+
+    /// Append an element to the back of the vector.
+    ///
+    /// The element is appended with `int_vector::resize` in a single FFI call. SDSL allocates exactly the words
+    /// which hold the elements, so the storage is reallocated whenever the vector grows into a new 64 bit word.
+    /// Prefer `extend_from_slice` to append many elements.
+    ///
+    /// Returns an error if `value` does not fit within `width()` bits, or if SDSL fails to allocate the storage.
+    /// # Arguments
+    /// * `value` - New element value.
+    pub fn push(&mut self, value: u64) -> Result<()> {
+        check_width(value, self.width())?;
+        (self.interface.push_back)(self.ptr, value);
+        self.len_cache.invalidate();
+        sdsl_c::check_last_error()
+    }
+
+    /// Remove the last element of the vector and return it, or `None` if the vector is empty.
+    pub fn pop(&mut self) -> Option<u64> {
+        if self.is_empty() {
+            return None;
+        }
+        let len = self.len();
+        let value = unsafe { self.get_unchecked(len - 1) } as u64;
+        self.shrink(len - 1);
+        Some(value)
+    }
+
+    /// Shorten the vector, keeping the first `len` elements.
+    ///
+    /// Has no effect if `len` is greater than or equal to the vector's current length.
+    /// # Arguments
+    /// * `len` - Number of elements to keep.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.shrink(len);
+        }
+    }
+
+    /// Reserve capacity for at least `additional` more elements.
+    ///
+    /// Has no effect: SDSL `int_vector` allocates exactly the words which hold its elements and keeps no spare
+    /// capacity. Provided for parity with `Vec`.
+    /// # Arguments
+    /// * `_additional` - Number of elements to reserve space for.
+    pub fn reserve(&mut self, _additional: usize) {}
+
+    /// Release capacity which is not required to hold the current elements.
+    ///
+    /// Has no effect: SDSL `int_vector` keeps no spare capacity, see `reserve`.
+    pub fn shrink_to_fit(&mut self) {}
+
+    /// Swap two elements of the vector.
+    /// # Arguments
+    /// * `a` - An index in range $ [0, \mathrm{len}()) $.
+    /// * `b` - An index in range $ [0, \mathrm{len}()) $.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let len = self.len();
+        assert!(
+            a < len && b < len,
+            "Swap indexes ({}, {}) out of bounds for vector length {}.",
+            a,
+            b,
+            len
+        );
+        unsafe {
+            let value_a = self.get_unchecked(a);
+            let value_b = self.get_unchecked(b);
+            self.set_unchecked(a, value_b);
+            self.set_unchecked(b, value_a);
+        }
+    }
+
+    /// Append all elements of a slice to the back of the vector.
+    ///
+    /// Returns an error, leaving the vector unchanged, if any value does not fit within `width()` bits or if SDSL fails
+    /// to allocate the storage.
+    /// # Arguments
+    /// * `values` - New element values.
+    pub fn extend_from_slice(&mut self, values: &[u64]) -> Result<()> {
+        let width = self.width();
+        for value in values {
+            check_width(*value, width)?;
+        }
+        let len = self.len();
+        self.resize(len + values.len())?;
+        self.set_range(len, values);
+        Ok(())
     }
 
     /// The number of elements in the vector.
     pub fn len(&self) -> usize {
-        (self.interface.len)(self.ptr)
+        self.len_cache.get(|| (self.interface.len)(self.ptr))
     }
 
     /// Maximum size of the vector.
@@ -145,18 +440,51 @@ impl<const WIDTH: u8> IntVector<WIDTH> {
     /// * `width` - New width of the integers.
     pub fn set_width(&mut self, width: usize) -> Result<()> {
         if WIDTH != 0 {
-            Err(format_err!(
-                "WIDTH is non-zero. Width is therefore immutable."
+            Err(Error::InvalidArgument(
+                "WIDTH is non-zero. Width is therefore immutable.".to_string(),
             ))
         } else {
-            Ok((self.interface.set_width)(self.ptr, width))
+            (self.interface.set_width)(self.ptr, width);
+            self.len_cache.invalidate();
+            Ok(())
         }
     }
 
     /// Returns an iterator over the vector values.
-    pub fn iter(&self) -> common::VectorIterator<usize, Self> {
+    pub fn iter(&self) -> common::VectorIterator<'_, usize, Self> {
         common::VectorIterator::new(&self, self.len())
     }
+
+    /// Returns a read-only view of the elements with indexes in `range`.
+    ///
+    /// Panics if `range` is out of bounds.
+    /// # Arguments
+    /// * `range` - Index range within $ [0, \mathrm{len}()) $.
+    pub fn slice(&self, range: std::ops::Range<usize>) -> common::VectorSlice<'_, usize, Self> {
+        common::VectorSlice::new(self, self.len(), range)
+    }
+}
+
+/// A proxy for reading and writing a single element of an `IntVector`.
+///
+/// Created by `IntVector::at_mut`.
+pub struct IntVectorElementMut<'a, const WIDTH: u8> {
+    vector: &'a mut IntVector<WIDTH>,
+    index: usize,
+}
+
+impl<'a, const WIDTH: u8> IntVectorElementMut<'a, WIDTH> {
+    /// Get the element value.
+    pub fn get(&self) -> usize {
+        unsafe { self.vector.get_unchecked(self.index) }
+    }
+
+    /// Set the element value.
+    /// # Arguments
+    /// * `value` - New element value.
+    pub fn set(&mut self, value: usize) {
+        unsafe { self.vector.set_unchecked(self.index, value) }
+    }
 }
 
 impl<const WIDTH: u8> common::util::Util for IntVector<WIDTH> {
@@ -175,22 +503,27 @@ impl<const WIDTH: u8> common::Ptr for IntVector<WIDTH> {
     fn ptr(&self) -> &common::VoidPtr {
         &self.ptr
     }
+
+    fn ptr_mut(&mut self) -> &common::VoidPtr {
+        self.len_cache.invalidate();
+        &self.ptr
+    }
 }
 
-impl<'a, const WIDTH: u8> common::Id for IntVector<WIDTH> {
+impl<const WIDTH: u8> common::Id for IntVector<WIDTH> {
     fn id() -> Result<String> {
-        let meta = Box::new(meta::int_vector::IntVectorMeta::new()) as Box<dyn meta::common::Meta>;
-        let parameters_c_code = Self::parameters_c_code()?;
-        let id = sdsl_c::specification::get_id(&meta.c_code(&parameters_c_code)?)?;
+        let id = sdsl_c::specification::get_id(&Self::c_code()?).map_err(Error::specification)?;
+        sdsl_c::manifest::check_instantiation(&id, std::any::type_name::<Self>())?;
         Ok(id)
     }
 }
 
-impl<'a, const WIDTH: u8> common::Code for IntVector<WIDTH> {
+impl<const WIDTH: u8> common::Code for IntVector<WIDTH> {
     fn c_code() -> Result<String> {
         let meta = Box::new(meta::int_vector::IntVectorMeta::new()) as Box<dyn meta::common::Meta>;
         let parameters_c_code = Self::parameters_c_code()?;
-        Ok(meta.c_code(&parameters_c_code)?)
+        meta.c_code(&parameters_c_code)
+            .map_err(Error::specification)
     }
 
     fn parameters_c_code() -> Result<Vec<String>> {
@@ -202,6 +535,34 @@ impl<const WIDTH: u8> common::IterGet<usize> for IntVector<WIDTH> {
     fn iter_get(&self, index: usize) -> usize {
         (self.interface.get)(self.ptr, index)
     }
+
+    fn iter_get_range(&self, index: usize, len: usize, values: &mut Vec<usize>) {
+        values.clear();
+        values.resize(len, 0);
+        // SDSL supports 64-bit targets only, where usize and u64 share their layout.
+        (self.interface.get_range)(self.ptr, index, len, values.as_mut_ptr() as *mut u64);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const WIDTH: u8> serde::Serialize for IntVector<WIDTH> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        let bytes = self.to_bytes().map_err(serde::ser::Error::custom)?;
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const WIDTH: u8> serde::Deserialize<'de> for IntVector<WIDTH> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let bytes = common::io::deserialize_bytes(deserializer)?;
+        Self::from_bytes(&bytes).map_err(serde::de::Error::custom)
+    }
 }
 
 impl<const WIDTH: u8> Drop for IntVector<WIDTH> {
@@ -211,11 +572,10 @@ impl<const WIDTH: u8> Drop for IntVector<WIDTH> {
 }
 
 impl<const WIDTH: u8> Clone for IntVector<WIDTH> {
+    /// Panics if SDSL fails to allocate the copy, see `try_clone`.
     fn clone(&self) -> Self {
-        Self {
-            ptr: (self.interface.clone)(self.ptr),
-            interface: self.interface.clone(),
-        }
+        self.try_clone()
+            .unwrap_or_else(|error| panic!("Failed to clone IntVector: {}", error))
     }
 }
 
@@ -235,6 +595,50 @@ impl<const WIDTH: u8> IntoIterator for IntVector<WIDTH> {
     }
 }
 
+impl<const WIDTH: u8> std::iter::FromIterator<u64> for IntVector<WIDTH> {
+    /// Collect values into a vector. Elements have 64 bit widths if `WIDTH` is 0.
+    ///
+    /// Panics if a value does not fit within `WIDTH` bits.
+    fn from_iter<T: IntoIterator<Item = u64>>(iter: T) -> Self {
+        let values: Vec<u64> = iter.into_iter().collect();
+        Self::from(values.as_slice())
+    }
+}
+
+impl<const WIDTH: u8> From<&[u64]> for IntVector<WIDTH> {
+    /// Construct a vector from a slice. Elements have 64 bit widths if `WIDTH` is 0.
+    ///
+    /// Panics if a value does not fit within `WIDTH` bits.
+    fn from(values: &[u64]) -> Self {
+        Self::from_values(values, Self::collection_width())
+            .unwrap_or_else(|error| panic!("Failed to construct IntVector from values: {}", error))
+    }
+}
+
+impl<const WIDTH: u8> Extend<u64> for IntVector<WIDTH> {
+    /// Append values to the back of the vector.
+    ///
+    /// Panics, leaving the vector unchanged, if a value does not fit within `width()` bits.
+    fn extend<T: IntoIterator<Item = u64>>(&mut self, iter: T) {
+        let values: Vec<u64> = iter.into_iter().collect();
+        if let Err(error) = self.extend_from_slice(&values) {
+            panic!("Failed to extend IntVector: {}", error);
+        }
+    }
+}
+
+/// Returns an error if `value` does not fit within `width` bits.
+fn check_width(value: u64, width: u8) -> Result<()> {
+    if width < 64 && value >> width != 0 {
+        Err(Error::InvalidArgument(format!(
+            "Value {} does not fit within integer width {}.",
+            value, width
+        )))
+    } else {
+        Ok(())
+    }
+}
+
 #[derive(Clone)]
 struct Interface {
     create: extern "C" fn(usize, usize, u8) -> common::VoidPtr,
@@ -244,6 +648,7 @@ struct Interface {
 
     resize: extern "C" fn(common::VoidPtr, usize),
     bit_resize: extern "C" fn(common::VoidPtr, usize),
+    push_back: extern "C" fn(common::VoidPtr, u64),
     len: extern "C" fn(common::VoidPtr) -> usize,
     max_size: extern "C" fn(common::VoidPtr) -> usize,
     bit_size: extern "C" fn(common::VoidPtr) -> usize,
@@ -255,25 +660,28 @@ struct Interface {
 
     get: extern "C" fn(common::VoidPtr, usize) -> usize,
     set: extern "C" fn(common::VoidPtr, usize, usize),
+    get_range: extern "C" fn(common::VoidPtr, usize, usize, *mut u64),
+    set_range: extern "C" fn(common::VoidPtr, usize, usize, *const u64),
 
     pub io: common::io::Interface,
     util: common::util::Interface,
-    _lib: std::sync::Arc<sharedlib::Lib>,
+    _lib: std::sync::Arc<sdsl_c::Library>,
 }
 
 impl Interface {
     pub fn new(id: &str) -> Result<Self> {
-        let lib = sdsl_c::LIB.clone();
+        let lib = sdsl_c::lib()?;
         let builder = sdsl_c::FunctionBuilder::new(Some("int_vector"), id, lib.clone());
 
         Ok(Self {
-            create: builder.get("create")?,
+            create: builder.get("try_create")?,
             drop: builder.get("destroy")?,
-            clone: builder.get("copy")?,
+            clone: builder.get("try_copy")?,
             is_empty: builder.get("empty")?,
 
-            resize: builder.get("resize")?,
-            bit_resize: builder.get("bit_resize")?,
+            resize: builder.get("try_resize")?,
+            bit_resize: builder.get("try_bit_resize")?,
+            push_back: builder.get("push_back")?,
             len: builder.get("size")?,
             max_size: builder.get("max_size")?,
             bit_size: builder.get("bit_size")?,
@@ -281,6 +689,8 @@ impl Interface {
 
             get: builder.get("get_element")?,
             set: builder.get("set_element")?,
+            get_range: builder.get("get_range")?,
+            set_range: builder.get("set_range")?,
 
             data: builder.get("data")?,
             width: builder.get("width")?,
@@ -315,11 +725,7 @@ macro_rules! int_vector {
         {
             let _size = int_vector!(@count $($key),*);
             let mut _vec = sdsl::int_vectors::IntVector::<0>::new(_size, 0, Some(64))?;
-            let mut i = 0;
-            $(
-                _vec.set(i, $key);
-                i += 1;
-            )*
+            _vec.set_range(0, &[$(($key) as u64),*]);
             _vec
         }
     };
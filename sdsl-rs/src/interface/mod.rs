@@ -11,12 +11,14 @@ pub mod crate_export {
     pub use crate::interface::common::util::crate_export as util;
     pub use crate::interface::wavelet_trees::crate_export as wavelet_trees;
 
+    pub use crate::interface::common::VectorSlice;
+
     pub mod bit_patterns {
         pub use crate::interface::common::bit_patterns::{P0, P01, P1, P10};
     }
 
     pub mod int_vectors {
-        pub use crate::interface::int_vector::IntVector;
+        pub use crate::interface::int_vector::{IntVector, IntVectorElementMut};
     }
 
     pub mod rank_supports {
@@ -1,6 +1,6 @@
 use crate::backend::sdsl_c;
+use crate::error::{Error, Result};
 use crate::meta;
-use anyhow::{format_err, Result};
 
 use crate::interface::common::{self, Code, Id, Ptr};
 
@@ -39,6 +39,7 @@ pub struct RankSupportV<'a, BitPattern: common::bit_patterns::BitPattern> {
     // Dummy field to retain reference to bit vector.
     _bit_vector: Option<&'a super::bit_vectors::bit_vector::BitVector>,
     ptr: common::VoidPtr,
+    len_cache: common::LenCache,
     interface: Interface,
 }
 
@@ -50,11 +51,13 @@ impl<'a, BitPattern: common::bit_patterns::BitPattern> RankSupportV<'a, BitPatte
         let id = Self::id()?;
         let interface = Interface::new(&id)?;
         let ptr = (interface.create)(*bit_vector.ptr());
+        sdsl_c::check_last_error()?;
 
         Ok(Self {
             _bp: None,
             _bit_vector: Some(&bit_vector),
             ptr,
+            len_cache: common::LenCache::default(),
             interface,
         })
     }
@@ -63,40 +66,157 @@ impl<'a, BitPattern: common::bit_patterns::BitPattern> RankSupportV<'a, BitPatte
         let id = Self::id()?;
         let interface = Interface::new(&id)?;
         let ptr = (interface.create)(std::ptr::null());
+        sdsl_c::check_last_error()?;
 
         Ok(Self {
             _bp: None,
             _bit_vector: None,
             ptr,
+            len_cache: common::LenCache::default(),
             interface,
         })
     }
 
+    /// Construct a structure for `bit_vector` and replace its content by calling `load`.
+    fn load_for<F: FnOnce(&mut Self) -> Result<()>>(
+        bit_vector: &'a super::bit_vectors::bit_vector::BitVector,
+        load: F,
+    ) -> Result<Self> {
+        let mut structure = Self::default()?;
+        structure._bit_vector = Some(bit_vector);
+        load(&mut structure)?;
+        // Loading resets the supported bit vector.
+        (structure.interface.set_vector)(structure.ptr, *bit_vector.ptr());
+        Ok(structure)
+    }
+
     /// Load from file.
+    ///
+    /// The file does not contain the bit vector, the structure supports `bit_vector` instead.
     /// # Arguments
     /// * `path` - File path.
-    pub fn from_file(path: &std::path::PathBuf) -> Result<Self> {
-        let rs = Self::default()?;
+    /// * `bit_vector` - Bit vector for which the structure was stored.
+    pub fn from_file(
+        path: &std::path::PathBuf,
+        bit_vector: &'a super::bit_vectors::bit_vector::BitVector,
+    ) -> Result<Self> {
+        Self::load_for(bit_vector, |structure| {
+            common::io::load_from_file(structure, path)
+        })
+    }
+
+    /// Write the structure to a stream in SDSL's serialization format.
+    ///
+    /// Returns the number of bytes written.
+    /// # Arguments
+    /// * `writer` - Destination stream.
+    pub fn serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<usize> {
+        common::io::serialize(self, writer)
+    }
 
-        let path = path
-            .to_str()
-            .ok_or(format_err!("Failed to convert PathBuf into str."))?;
-        let path = std::ffi::CString::new(path)?;
+    /// Load a structure from a stream in SDSL's serialization format.
+    ///
+    /// The stream does not contain the bit vector, the structure supports `bit_vector` instead.
+    /// # Arguments
+    /// * `reader` - Source stream.
+    /// * `bit_vector` - Bit vector for which the structure was serialized.
+    pub fn load<R: std::io::Read>(
+        reader: &mut R,
+        bit_vector: &'a super::bit_vectors::bit_vector::BitVector,
+    ) -> Result<Self> {
+        Self::load_for(bit_vector, |structure| common::io::load(structure, reader))
+    }
 
-        (rs.interface.io.load_from_file)(rs.ptr, path.as_ptr());
-        Ok(rs)
+    /// Returns the structure in SDSL's serialization format.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        common::io::to_bytes(self)
+    }
+
+    /// Load a structure from a buffer in SDSL's serialization format.
+    ///
+    /// The buffer does not contain the bit vector, the structure supports `bit_vector` instead.
+    /// # Arguments
+    /// * `bytes` - Serialized structure.
+    /// * `bit_vector` - Bit vector for which the structure was serialized.
+    pub fn from_bytes(
+        bytes: &[u8],
+        bit_vector: &'a super::bit_vectors::bit_vector::BitVector,
+    ) -> Result<Self> {
+        Self::load_for(bit_vector, |structure| {
+            common::io::load_from_bytes(structure, bytes)
+        })
+    }
+
+    /// Returns a seed which deserializes the structure for `bit_vector` with serde's `DeserializeSeed`.
+    ///
+    /// The structure does not implement `Deserialize`, since the serialized structure does not contain the bit vector.
+    /// # Arguments
+    /// * `bit_vector` - Bit vector for which the structure was serialized.
+    #[cfg(feature = "serde")]
+    pub fn deserialize_seed(
+        bit_vector: &'a super::bit_vectors::bit_vector::BitVector,
+    ) -> common::io::SupportSeed<'a, Self> {
+        common::io::SupportSeed::new(bit_vector, Self::from_bytes)
     }
 
     /// Get rank at index.
+    ///
+    /// Panics if `index` is out of bounds, see `try_rank` for a checked alternative.
     /// # Arguments
-    /// * `index` - An index in range $ [0, \mathrm{len}()) $.
+    /// * `index` - An index in range $ [0, \mathrm{len}()] $.
     pub fn rank(&self, index: usize) -> usize {
+        self.try_rank(index)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Get rank at index or an error if `index` is out of bounds.
+    /// # Arguments
+    /// * `index` - An index in range $ [0, \mathrm{len}()] $.
+    pub fn try_rank(&self, index: usize) -> Result<usize> {
+        // The rank of the full bit vector, at index len(), is a valid query.
+        crate::error::check_index(index, self.len() + 1)?;
+        Ok(unsafe { self.rank_unchecked(index) })
+    }
+
+    /// Get rank at index without bounds checking.
+    /// # Safety
+    /// `index` must be in range $ [0, \mathrm{len}()] $, otherwise memory outside of the bit vector is read.
+    /// # Arguments
+    /// * `index` - An index in range $ [0, \mathrm{len}()] $.
+    pub unsafe fn rank_unchecked(&self, index: usize) -> usize {
         (self.interface.rank)(self.ptr, index)
     }
 
+    /// Get ranks at multiple indexes using a single FFI call.
+    ///
+    /// Panics if any index is out of bounds.
+    /// # Arguments
+    /// * `indexes` - Indexes in range $ [0, \mathrm{len}()] $.
+    pub fn rank_many(&self, indexes: &[usize]) -> Vec<usize> {
+        let len = self.len();
+        for index in indexes {
+            if let Err(error) = crate::error::check_index(*index, len + 1) {
+                panic!("{}", error);
+            }
+        }
+        let mut ranks = vec![0; indexes.len()];
+        (self.interface.rank_many)(
+            self.ptr,
+            indexes.as_ptr(),
+            indexes.len(),
+            ranks.as_mut_ptr(),
+        );
+        ranks
+    }
+
     /// The number of elements in the vector.
     pub fn len(&self) -> usize {
-        (self.interface.len)(self.ptr)
+        self.len_cache.get(|| (self.interface.len)(self.ptr))
+    }
+
+    /// Returns true if the vector is empty, otherwise returns false.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 }
 
@@ -114,14 +234,17 @@ impl<'a, BitPattern: common::bit_patterns::BitPattern> common::Ptr
     fn ptr(&self) -> &common::VoidPtr {
         &self.ptr
     }
+
+    fn ptr_mut(&mut self) -> &common::VoidPtr {
+        self.len_cache.invalidate();
+        &self.ptr
+    }
 }
 
 impl<'a, BitPattern: common::bit_patterns::BitPattern> common::Id for RankSupportV<'a, BitPattern> {
     fn id() -> Result<String> {
-        let meta =
-            Box::new(meta::rank_support_v::RankSupportVMeta::new()) as Box<dyn meta::common::Meta>;
-        let parameters_c_code = Self::parameters_c_code()?;
-        let id = sdsl_c::specification::get_id(&meta.c_code(&parameters_c_code)?)?;
+        let id = sdsl_c::specification::get_id(&Self::c_code()?).map_err(Error::specification)?;
+        sdsl_c::manifest::check_instantiation(&id, std::any::type_name::<Self>())?;
         Ok(id)
     }
 }
@@ -133,7 +256,8 @@ impl<'a, BitPattern: common::bit_patterns::BitPattern> common::Code
         let meta =
             Box::new(meta::rank_support_v::RankSupportVMeta::new()) as Box<dyn meta::common::Meta>;
         let parameters_c_code = Self::parameters_c_code()?;
-        Ok(meta.c_code(&parameters_c_code)?)
+        meta.c_code(&parameters_c_code)
+            .map_err(Error::specification)
     }
 
     fn parameters_c_code() -> Result<Vec<String>> {
@@ -141,6 +265,19 @@ impl<'a, BitPattern: common::bit_patterns::BitPattern> common::Code
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'a, BitPattern: common::bit_patterns::BitPattern> serde::Serialize
+    for RankSupportV<'a, BitPattern>
+{
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        let bytes = self.to_bytes().map_err(serde::ser::Error::custom)?;
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
 impl<'a, BitPattern: common::bit_patterns::BitPattern> Drop for RankSupportV<'a, BitPattern> {
     fn drop(&mut self) {
         (self.interface.drop)(self.ptr)
@@ -151,24 +288,28 @@ impl<'a, BitPattern: common::bit_patterns::BitPattern> Drop for RankSupportV<'a,
 struct Interface {
     create: extern "C" fn(common::VoidPtr) -> common::VoidPtr,
     drop: extern "C" fn(common::VoidPtr),
+    set_vector: extern "C" fn(common::VoidPtr, common::VoidPtr),
 
     len: extern "C" fn(common::VoidPtr) -> usize,
     rank: extern "C" fn(common::VoidPtr, usize) -> usize,
+    rank_many: extern "C" fn(common::VoidPtr, *const usize, usize, *mut usize),
 
     pub io: common::io::Interface,
-    _lib: std::sync::Arc<sharedlib::Lib>,
+    _lib: std::sync::Arc<sdsl_c::Library>,
 }
 
 impl Interface {
     pub fn new(id: &str) -> Result<Self> {
-        let lib = sdsl_c::LIB.clone();
+        let lib = sdsl_c::lib()?;
         let builder = sdsl_c::FunctionBuilder::new(Some("rank_support_v"), id, lib.clone());
 
         Ok(Self {
-            create: builder.get("create")?,
+            create: builder.get("try_create")?,
             drop: builder.get("destroy")?,
+            set_vector: builder.get("set_vector")?,
 
             rank: builder.get("rank")?,
+            rank_many: builder.get("rank_many")?,
             len: builder.get("size")?,
 
             io: common::io::Interface::new(&id)?,
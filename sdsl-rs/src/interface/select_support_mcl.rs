@@ -1,6 +1,6 @@
 use crate::backend::sdsl_c;
+use crate::error::{Error, Result};
 use crate::meta;
-use anyhow::{format_err, Result};
 
 use crate::interface::common::{self, Code, Id, Ptr};
 
@@ -48,8 +48,10 @@ use crate::interface::common::{self, Code, Id, Ptr};
 pub struct SelectSupportMcl<'a, BitPattern: common::bit_patterns::BitPattern> {
     // Dummy field so BitPattern is used, always None.
     _bp: Option<BitPattern>,
-    // Dummy field to retain reference to bit vector.
-    _bit_vector: Option<&'a super::bit_vectors::bit_vector::BitVector>,
+    // Supported bit vector, None if unknown.
+    bit_vector: Option<&'a super::bit_vectors::bit_vector::BitVector>,
+    // Number of bit pattern instances in the bit vector, counted on first use.
+    count: std::cell::Cell<Option<usize>>,
     ptr: common::VoidPtr,
     interface: Interface,
 }
@@ -62,10 +64,12 @@ impl<'a, BitPattern: common::bit_patterns::BitPattern> SelectSupportMcl<'a, BitP
         let id = Self::id()?;
         let interface = Interface::new(&id)?;
         let ptr = (interface.create)(*bit_vector.ptr());
+        sdsl_c::check_last_error()?;
 
         Ok(Self {
             _bp: None,
-            _bit_vector: Some(&bit_vector),
+            bit_vector: Some(bit_vector),
+            count: std::cell::Cell::new(None),
             ptr,
             interface,
         })
@@ -75,36 +79,158 @@ impl<'a, BitPattern: common::bit_patterns::BitPattern> SelectSupportMcl<'a, BitP
         let id = Self::id()?;
         let interface = Interface::new(&id)?;
         let ptr = (interface.create)(std::ptr::null());
+        sdsl_c::check_last_error()?;
 
         Ok(Self {
             _bp: None,
-            _bit_vector: None,
+            bit_vector: None,
+            count: std::cell::Cell::new(None),
             ptr,
             interface,
         })
     }
 
+    /// Construct a structure for `bit_vector` and replace its content by calling `load`.
+    fn load_for<F: FnOnce(&mut Self) -> Result<()>>(
+        bit_vector: &'a super::bit_vectors::bit_vector::BitVector,
+        load: F,
+    ) -> Result<Self> {
+        let mut structure = Self::default()?;
+        structure.bit_vector = Some(bit_vector);
+        load(&mut structure)?;
+        // Loading resets the supported bit vector.
+        (structure.interface.set_vector)(structure.ptr, *bit_vector.ptr());
+        Ok(structure)
+    }
+
     /// Load from file.
+    ///
+    /// The file does not contain the bit vector, the structure supports `bit_vector` instead.
     /// # Arguments
     /// * `path` - File path.
-    pub fn from_file(path: &std::path::PathBuf) -> Result<Self> {
-        let rs = Self::default()?;
+    /// * `bit_vector` - Bit vector for which the structure was stored.
+    pub fn from_file(
+        path: &std::path::PathBuf,
+        bit_vector: &'a super::bit_vectors::bit_vector::BitVector,
+    ) -> Result<Self> {
+        Self::load_for(bit_vector, |structure| {
+            common::io::load_from_file(structure, path)
+        })
+    }
+
+    /// Write the structure to a stream in SDSL's serialization format.
+    ///
+    /// Returns the number of bytes written.
+    /// # Arguments
+    /// * `writer` - Destination stream.
+    pub fn serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<usize> {
+        common::io::serialize(self, writer)
+    }
+
+    /// Load a structure from a stream in SDSL's serialization format.
+    ///
+    /// The stream does not contain the bit vector, the structure supports `bit_vector` instead.
+    /// # Arguments
+    /// * `reader` - Source stream.
+    /// * `bit_vector` - Bit vector for which the structure was serialized.
+    pub fn load<R: std::io::Read>(
+        reader: &mut R,
+        bit_vector: &'a super::bit_vectors::bit_vector::BitVector,
+    ) -> Result<Self> {
+        Self::load_for(bit_vector, |structure| common::io::load(structure, reader))
+    }
+
+    /// Returns the structure in SDSL's serialization format.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        common::io::to_bytes(self)
+    }
 
-        let path = path
-            .to_str()
-            .ok_or(format_err!("Failed to convert PathBuf into str."))?;
-        let path = std::ffi::CString::new(path)?;
+    /// Load a structure from a buffer in SDSL's serialization format.
+    ///
+    /// The buffer does not contain the bit vector, the structure supports `bit_vector` instead.
+    /// # Arguments
+    /// * `bytes` - Serialized structure.
+    /// * `bit_vector` - Bit vector for which the structure was serialized.
+    pub fn from_bytes(
+        bytes: &[u8],
+        bit_vector: &'a super::bit_vectors::bit_vector::BitVector,
+    ) -> Result<Self> {
+        Self::load_for(bit_vector, |structure| {
+            common::io::load_from_bytes(structure, bytes)
+        })
+    }
+
+    /// Returns a seed which deserializes the structure for `bit_vector` with serde's `DeserializeSeed`.
+    ///
+    /// The structure does not implement `Deserialize`, since the serialized structure does not contain the bit vector.
+    /// # Arguments
+    /// * `bit_vector` - Bit vector for which the structure was serialized.
+    #[cfg(feature = "serde")]
+    pub fn deserialize_seed(
+        bit_vector: &'a super::bit_vectors::bit_vector::BitVector,
+    ) -> common::io::SupportSeed<'a, Self> {
+        common::io::SupportSeed::new(bit_vector, Self::from_bytes)
+    }
 
-        (rs.interface.io.load_from_file)(rs.ptr, path.as_ptr());
-        Ok(rs)
+    /// Returns the number of bit pattern instances, or None if the bit vector is unknown.
+    fn count(&self) -> Option<usize> {
+        if let (None, Some(bit_vector)) = (self.count.get(), self.bit_vector) {
+            self.count
+                .set(Some((self.interface.count)(*bit_vector.ptr())));
+        }
+        self.count.get()
     }
 
     /// Returns the position of the i-th bit pattern instance in the bit vector.
+    ///
+    /// Panics if there is no i-th instance, see `try_select` for a checked alternative.
     /// # Arguments
-    /// * `index` - An index within the range of the supported bit vector.
+    /// * `index` - An instance number in range $ [1, m] $ where $ m $ is the number of bit pattern instances.
     pub fn select(&self, index: usize) -> usize {
+        self.try_select(index)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns the position of the i-th bit pattern instance in the bit vector or an error if there is no i-th instance.
+    ///
+    /// The number of instances is unknown for structures loaded from file, in which case only the lower bound is checked.
+    /// # Arguments
+    /// * `index` - An instance number in range $ [1, m] $ where $ m $ is the number of bit pattern instances.
+    pub fn try_select(&self, index: usize) -> Result<usize> {
+        crate::error::check_occurrence(index, self.count())?;
+        Ok(unsafe { self.select_unchecked(index) })
+    }
+
+    /// Returns the position of the i-th bit pattern instance in the bit vector without range checking.
+    /// # Safety
+    /// `index` must be in range $ [1, m] $ where $ m $ is the number of bit pattern instances, otherwise memory outside of the structure is read.
+    /// # Arguments
+    /// * `index` - An instance number in range $ [1, m] $ where $ m $ is the number of bit pattern instances.
+    pub unsafe fn select_unchecked(&self, index: usize) -> usize {
         (self.interface.select)(self.ptr, index)
     }
+
+    /// Returns the positions of multiple bit pattern instances using a single FFI call.
+    ///
+    /// Panics if any instance does not exist.
+    /// # Arguments
+    /// * `indexes` - Instance numbers in range $ [1, m] $ where $ m $ is the number of bit pattern instances.
+    pub fn select_many(&self, indexes: &[usize]) -> Vec<usize> {
+        let count = self.count();
+        for index in indexes {
+            if let Err(error) = crate::error::check_occurrence(*index, count) {
+                panic!("{}", error);
+            }
+        }
+        let mut positions = vec![0; indexes.len()];
+        (self.interface.select_many)(
+            self.ptr,
+            indexes.as_ptr(),
+            indexes.len(),
+            positions.as_mut_ptr(),
+        );
+        positions
+    }
 }
 
 impl<'a, BitPattern: common::bit_patterns::BitPattern> common::io::IO
@@ -127,10 +253,8 @@ impl<'a, BitPattern: common::bit_patterns::BitPattern> common::Id
     for SelectSupportMcl<'a, BitPattern>
 {
     fn id() -> Result<String> {
-        let meta = Box::new(meta::select_support_mcl::SelectSupportMclMeta::new())
-            as Box<dyn meta::common::Meta>;
-        let parameters_c_code = Self::parameters_c_code()?;
-        let id = sdsl_c::specification::get_id(&meta.c_code(&parameters_c_code)?)?;
+        let id = sdsl_c::specification::get_id(&Self::c_code()?).map_err(Error::specification)?;
+        sdsl_c::manifest::check_instantiation(&id, std::any::type_name::<Self>())?;
         Ok(id)
     }
 }
@@ -142,7 +266,8 @@ impl<'a, BitPattern: common::bit_patterns::BitPattern> common::Code
         let meta = Box::new(meta::select_support_mcl::SelectSupportMclMeta::new())
             as Box<dyn meta::common::Meta>;
         let parameters_c_code = Self::parameters_c_code()?;
-        Ok(meta.c_code(&parameters_c_code)?)
+        meta.c_code(&parameters_c_code)
+            .map_err(Error::specification)
     }
 
     fn parameters_c_code() -> Result<Vec<String>> {
@@ -150,6 +275,19 @@ impl<'a, BitPattern: common::bit_patterns::BitPattern> common::Code
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'a, BitPattern: common::bit_patterns::BitPattern> serde::Serialize
+    for SelectSupportMcl<'a, BitPattern>
+{
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        let bytes = self.to_bytes().map_err(serde::ser::Error::custom)?;
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
 impl<'a, BitPattern: common::bit_patterns::BitPattern> Drop for SelectSupportMcl<'a, BitPattern> {
     fn drop(&mut self) {
         (self.interface.drop)(self.ptr)
@@ -160,23 +298,29 @@ impl<'a, BitPattern: common::bit_patterns::BitPattern> Drop for SelectSupportMcl
 struct Interface {
     create: extern "C" fn(common::VoidPtr) -> common::VoidPtr,
     drop: extern "C" fn(common::VoidPtr),
+    set_vector: extern "C" fn(common::VoidPtr, common::VoidPtr),
 
     select: extern "C" fn(common::VoidPtr, usize) -> usize,
+    select_many: extern "C" fn(common::VoidPtr, *const usize, usize, *mut usize),
+    count: extern "C" fn(common::VoidPtr) -> usize,
 
     pub io: common::io::Interface,
-    _lib: std::sync::Arc<sharedlib::Lib>,
+    _lib: std::sync::Arc<sdsl_c::Library>,
 }
 
 impl Interface {
     pub fn new(id: &str) -> Result<Self> {
-        let lib = sdsl_c::LIB.clone();
+        let lib = sdsl_c::lib()?;
         let builder = sdsl_c::FunctionBuilder::new(Some("select_support_mcl"), id, lib.clone());
 
         Ok(Self {
-            create: builder.get("create")?,
+            create: builder.get("try_create")?,
             drop: builder.get("destroy")?,
+            set_vector: builder.get("set_vector")?,
 
             select: builder.get("select")?,
+            select_many: builder.get("select_many")?,
+            count: builder.get("count")?,
 
             io: common::io::Interface::new(&id)?,
             _lib: lib.clone(),
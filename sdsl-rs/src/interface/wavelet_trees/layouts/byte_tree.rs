@@ -1,5 +1,5 @@
+use crate::error::{Error, Result};
 use crate::meta;
-use anyhow::Result;
 
 use super::common;
 use crate::interface::common::Code;
@@ -13,7 +13,8 @@ impl<Layout: common::MemoryLayout> Code for ByteTree<Layout> {
         let meta = Box::new(meta::wavelet_trees::layouts::byte_tree::ByteTreeMeta::new())
             as Box<dyn meta::common::Meta>;
         let parameters_c_code = Self::parameters_c_code()?;
-        Ok(meta.c_code(&parameters_c_code)?)
+        meta.c_code(&parameters_c_code)
+            .map_err(Error::specification)
     }
 
     fn parameters_c_code() -> Result<Vec<String>> {
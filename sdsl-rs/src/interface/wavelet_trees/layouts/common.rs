@@ -1,9 +1,9 @@
+use crate::error::Result;
 use crate::interface::common;
-use anyhow::Result;
 
 pub trait TreeStrategy {
-    type Value: Clone + Copy;
-    type Size: Into<usize> + Clone + Copy;
+    type Value: Clone + Copy + Default;
+    type Size: Into<usize> + From<usize> + Clone + Copy;
     const LEX_ORDERED: bool;
 }
 
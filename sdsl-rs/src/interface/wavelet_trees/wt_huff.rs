@@ -1,6 +1,6 @@
+use crate::error::{Error, Result};
 use crate::meta;
 use crate::{backend::sdsl_c, interface::common::Ptr};
-use anyhow::{format_err, Result};
 
 use crate::interface::common::{self, Code, Id};
 use crate::interface::wavelet_trees::layouts;
@@ -89,18 +89,74 @@ where
     /// # Arguments
     /// * `path` - File path.
     pub fn from_file(path: &std::path::PathBuf) -> Result<Self> {
-        let path = path
-            .to_str()
-            .ok_or(format_err!("Failed to convert PathBuf into str."))?;
-        let path = std::ffi::CString::new(path)?;
+        let file_len = common::io::file_len(path)?;
+        let c_path = common::io::c_path(path)?;
 
         let id = Self::id()?;
         let interface = Interface::new(&id)?;
-        let ptr = (interface.from_file)(path.as_ptr());
+        let ptr = (interface.from_file)(c_path.as_ptr());
+        sdsl_c::check_last_error()?;
+        if ptr.is_null() {
+            return Err(Error::io(
+                std::io::ErrorKind::InvalidData,
+                format!(
+                    "Failed to load wavelet tree from {}: file is truncated or corrupt.",
+                    path.display()
+                ),
+            ));
+        }
         let wt = Self::new(interface, ptr)?;
+        common::io::check_loaded_len(&wt, path, file_len)?;
         Ok(wt)
     }
 
+    /// Write the wavelet tree to a stream in SDSL's serialization format.
+    ///
+    /// Returns the number of bytes written.
+    /// # Arguments
+    /// * `writer` - Destination stream.
+    pub fn serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<usize> {
+        common::io::serialize(self, writer)
+    }
+
+    /// Load a wavelet tree from a stream in SDSL's serialization format.
+    /// # Arguments
+    /// * `reader` - Source stream.
+    pub fn load<R: std::io::Read>(reader: &mut R) -> Result<Self> {
+        let mut wt = Self::default()?;
+        common::io::load(&mut wt, reader)?;
+        Ok(wt)
+    }
+
+    /// Returns the wavelet tree in SDSL's serialization format.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        common::io::to_bytes(self)
+    }
+
+    /// Load a wavelet tree from a buffer in SDSL's serialization format.
+    /// # Arguments
+    /// * `bytes` - Serialized wavelet tree.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut wt = Self::default()?;
+        common::io::load_from_bytes(&mut wt, bytes)?;
+        Ok(wt)
+    }
+
+    /// Returns a copy of the wavelet tree, or an error if SDSL fails to allocate it.
+    pub fn try_clone(&self) -> Result<Self> {
+        let ptr = (self.interface.clone)(self.ptr);
+        sdsl_c::check_last_error()?;
+        Self::new(self.interface.clone(), ptr)
+    }
+
+    fn default() -> Result<Self> {
+        let id = Self::id()?;
+        let interface = Interface::new(&id)?;
+        let ptr = (interface.create)();
+        sdsl_c::check_last_error()?;
+        Self::new(interface, ptr)
+    }
+
     /// Construct a Huffman-shaped wavelet tree from a string.
     /// # Arguments
     /// * `string` - Data string.
@@ -109,6 +165,7 @@ where
         let interface = Interface::new(&id)?;
         let c_string = std::ffi::CString::new(string)?;
         let ptr = (interface.from_string)(c_string.as_ptr());
+        sdsl_c::check_last_error()?;
         let wt = Self::new(interface, ptr)?;
         Ok(wt)
     }
@@ -122,6 +179,7 @@ where
         let id = Self::id()?;
         let interface = Interface::new(&id)?;
         let ptr = (interface.from_int_vector)(*int_vector.ptr());
+        sdsl_c::check_last_error()?;
         let wt = Self::new(interface, ptr)?;
         Ok(wt)
     }
@@ -135,6 +193,7 @@ where
         let id = Self::id()?;
         let interface = Interface::new(&id)?;
         let ptr = (interface.from_bit_vector)(*bit_vector.ptr());
+        sdsl_c::check_last_error()?;
         let wt = Self::new(interface, ptr)?;
         Ok(wt)
     }
@@ -172,6 +231,29 @@ where
         (self.interface.get)(self.ptr, index)
     }
 
+    /// Get multiple elements of the original vector using a single FFI call.
+    /// # Arguments
+    /// * `indexes` - Indexes in range $ [0, \mathrm{len}()) $.
+    pub fn get_many(&self, indexes: &[usize]) -> Vec<TreeStrategy::Value> {
+        let len = self.len();
+        for index in indexes {
+            assert!(
+                *index < len,
+                "Index {} exceeds wavelet tree length {}.",
+                index,
+                len
+            );
+        }
+        let mut values = vec![Default::default(); indexes.len()];
+        (self.interface.get_many)(
+            self.ptr,
+            indexes.as_ptr(),
+            indexes.len(),
+            values.as_mut_ptr(),
+        );
+        values
+    }
+
     /// Returns a count of the given symbol within the prefix $ [0, \mathrm{index}-1] $.
     ///
     /// The time complexity is $ \mathcal{O}(H_0) $ on average, where $ H_0 $ is the zero order entropy of the sequence.
@@ -202,11 +284,40 @@ where
     /// Returns the index of the i-th occurrence of the given symbol in the supported vector.
     ///
     /// The time complexity is $ \mathcal{O}(H_0) $ on average, where $ H_0 $ is the zero order entropy of the sequence.
-    /// Precondition: $ 1 \leq \mathrm{index} \leq \mathrm{rank}(\mathrm{len}(), \mathrm{symbol}) $.
+    /// Panics unless $ 1 \leq i \leq \mathrm{rank}(\mathrm{len}(), \mathrm{symbol}) $, see `try_select` for a checked alternative.
     /// # Arguments
     /// * `i` - i-th symbol occurrence.
     /// * `symbol` - Symbol.
     pub fn select(&self, i: TreeStrategy::Size, symbol: TreeStrategy::Value) -> TreeStrategy::Size {
+        self.try_select(i, symbol)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns the index of the i-th occurrence of the given symbol or an error if there is no i-th occurrence.
+    /// # Arguments
+    /// * `i` - i-th symbol occurrence.
+    /// * `symbol` - Symbol.
+    pub fn try_select(
+        &self,
+        i: TreeStrategy::Size,
+        symbol: TreeStrategy::Value,
+    ) -> Result<TreeStrategy::Size> {
+        let count = self.rank(self.len().into(), symbol);
+        crate::error::check_occurrence(i.into(), Some(count.into()))?;
+        Ok(unsafe { self.select_unchecked(i, symbol) })
+    }
+
+    /// Returns the index of the i-th occurrence of the given symbol without range checking.
+    /// # Safety
+    /// Requires $ 1 \leq i \leq \mathrm{rank}(\mathrm{len}(), \mathrm{symbol}) $, otherwise memory outside of the tree is read.
+    /// # Arguments
+    /// * `i` - i-th symbol occurrence.
+    /// * `symbol` - Symbol.
+    pub unsafe fn select_unchecked(
+        &self,
+        i: TreeStrategy::Size,
+        symbol: TreeStrategy::Value,
+    ) -> TreeStrategy::Size {
         (self.interface.select)(self.ptr, i, symbol)
     }
 
@@ -222,7 +333,7 @@ where
         &self,
         start_index: TreeStrategy::Size,
         end_index: TreeStrategy::Size,
-    ) -> IntervalSymbols<TreeStrategy::Value, TreeStrategy::Size> {
+    ) -> IntervalSymbols<'_, TreeStrategy::Value, TreeStrategy::Size> {
         let result = (self.interface.interval_symbols)(self.ptr, start_index, end_index);
         IntervalSymbols {
             interval_alphabet_size: result.interval_alphabet_size,
@@ -309,7 +420,7 @@ where
     }
 
     /// Returns an iterator over the vector that was used in constructing the wavelet tree.
-    pub fn iter(&self) -> common::VectorIterator<TreeStrategy::Value, Self> {
+    pub fn iter(&self) -> common::VectorIterator<'_, TreeStrategy::Value, Self> {
         common::VectorIterator::new(&self, self.len())
     }
 }
@@ -352,10 +463,8 @@ where
     TreeStrategy: layouts::common::TreeStrategy + common::Code + 'a,
 {
     fn id() -> Result<String> {
-        let meta = Box::new(meta::wavelet_trees::wt_huff::WtHuffMeta::new())
-            as Box<dyn meta::common::Meta>;
-        let parameters_c_code = Self::parameters_c_code()?;
-        let id = sdsl_c::specification::get_id(&meta.c_code(&parameters_c_code)?)?;
+        let id = sdsl_c::specification::get_id(&Self::c_code()?).map_err(Error::specification)?;
+        sdsl_c::manifest::check_instantiation(&id, std::any::type_name::<Self>())?;
         Ok(id)
     }
 }
@@ -373,7 +482,8 @@ where
         let meta = Box::new(meta::wavelet_trees::wt_huff::WtHuffMeta::new())
             as Box<dyn meta::common::Meta>;
         let parameters_c_code = Self::parameters_c_code()?;
-        Ok(meta.c_code(&parameters_c_code)?)
+        meta.c_code(&parameters_c_code)
+            .map_err(Error::specification)
     }
 
     fn parameters_c_code() -> Result<Vec<String>> {
@@ -400,6 +510,50 @@ where
     fn iter_get(&self, index: usize) -> TreeStrategy::Value {
         (self.interface.get)(self.ptr, index)
     }
+
+    fn iter_get_range(&self, index: usize, len: usize, values: &mut Vec<TreeStrategy::Value>) {
+        values.clear();
+        values.resize(len, Default::default());
+        (self.interface.get_range)(self.ptr, index, len, values.as_mut_ptr());
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a, BitVector, RankSupport1, SelectSupport1, SelectSupport0, TreeStrategy> serde::Serialize
+    for WtHuff<'a, BitVector, RankSupport1, SelectSupport1, SelectSupport0, TreeStrategy>
+where
+    BitVector: common::Code + 'a,
+    RankSupport1: common::Code,
+    SelectSupport1: common::Code,
+    SelectSupport0: common::Code,
+    TreeStrategy: layouts::common::TreeStrategy + common::Code + 'a,
+{
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        let bytes = self.to_bytes().map_err(serde::ser::Error::custom)?;
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, 'a, BitVector, RankSupport1, SelectSupport1, SelectSupport0, TreeStrategy>
+    serde::Deserialize<'de>
+    for WtHuff<'a, BitVector, RankSupport1, SelectSupport1, SelectSupport0, TreeStrategy>
+where
+    BitVector: common::Code + 'a,
+    RankSupport1: common::Code,
+    SelectSupport1: common::Code,
+    SelectSupport0: common::Code,
+    TreeStrategy: layouts::common::TreeStrategy + common::Code + 'a,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let bytes = common::io::deserialize_bytes(deserializer)?;
+        Self::from_bytes(&bytes).map_err(serde::de::Error::custom)
+    }
 }
 
 impl<'a, BitVector, RankSupport1, SelectSupport1, SelectSupport0, TreeStrategy> Drop
@@ -419,23 +573,16 @@ where
 impl<'a, BitVector, RankSupport1, SelectSupport1, SelectSupport0, TreeStrategy> Clone
     for WtHuff<'a, BitVector, RankSupport1, SelectSupport1, SelectSupport0, TreeStrategy>
 where
-    BitVector: common::Code,
+    BitVector: common::Code + 'a,
     RankSupport1: common::Code,
     SelectSupport1: common::Code,
     SelectSupport0: common::Code,
-    TreeStrategy: layouts::common::TreeStrategy + common::Code,
+    TreeStrategy: layouts::common::TreeStrategy + common::Code + 'a,
 {
+    /// Panics if SDSL fails to allocate the copy, see `try_clone`.
     fn clone(&self) -> Self {
-        Self {
-            _bs: None,
-            _rs1: &None,
-            _ss1: &None,
-            _ss0: &None,
-            _ts: None,
-
-            ptr: (self.interface.clone)(self.ptr),
-            interface: self.interface.clone(),
-        }
+        self.try_clone()
+            .unwrap_or_else(|error| panic!("Failed to clone WtHuff: {}", error))
     }
 }
 
@@ -506,6 +653,8 @@ struct Interface<Value, Size> {
     len: extern "C" fn(common::VoidPtr) -> usize,
     is_empty: extern "C" fn(common::VoidPtr) -> bool,
     get: extern "C" fn(common::VoidPtr, usize) -> Value,
+    get_many: extern "C" fn(common::VoidPtr, *const usize, usize, *mut Value),
+    get_range: extern "C" fn(common::VoidPtr, usize, usize, *mut Value),
     rank: extern "C" fn(common::VoidPtr, Size, Value) -> Size,
     inverse_select: extern "C" fn(common::VoidPtr, Size) -> common::Pair<Size, Value>,
     select: extern "C" fn(common::VoidPtr, Size, Value) -> Size,
@@ -519,26 +668,28 @@ struct Interface<Value, Size> {
     alphabet_size: extern "C" fn(common::VoidPtr) -> Size,
 
     pub io: common::io::Interface,
-    _lib: std::sync::Arc<sharedlib::Lib>,
+    _lib: std::sync::Arc<sdsl_c::Library>,
 }
 
 impl<Value, Size> Interface<Value, Size> {
     pub fn new(id: &str) -> Result<Self> {
-        let lib = sdsl_c::LIB.clone();
+        let lib = sdsl_c::lib()?;
         let builder = sdsl_c::FunctionBuilder::new(Some("wt_huff"), id, lib.clone());
 
         Ok(Self {
-            create: builder.get("create")?,
-            from_file: builder.get("from_file")?,
-            from_string: builder.get("from_string")?,
-            from_int_vector: builder.get("from_int_vector")?,
-            from_bit_vector: builder.get("from_bit_vector")?,
+            create: builder.get("try_create")?,
+            from_file: builder.get("try_from_file")?,
+            from_string: builder.get("try_from_string")?,
+            from_int_vector: builder.get("try_from_int_vector")?,
+            from_bit_vector: builder.get("try_from_bit_vector")?,
             drop: builder.get("destroy")?,
-            clone: builder.get("copy")?,
+            clone: builder.get("try_copy")?,
 
             len: builder.get("size")?,
             is_empty: builder.get("empty")?,
             get: builder.get("get_element")?,
+            get_many: builder.get("get_many")?,
+            get_range: builder.get("get_range")?,
             rank: builder.get("rank")?,
             inverse_select: builder.get("inverse_select")?,
             select: builder.get("select")?,
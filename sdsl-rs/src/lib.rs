@@ -1,8 +1,22 @@
 //! A Rust interface for the Succinct Data Structure Library ([SDSL-lite](https://github.com/simongog/sdsl-lite)).
+//!
+//! # Serde
+//!
+//! The optional `serde` feature implements `Serialize` and `Deserialize` for all structures, using SDSL's
+//! serialization format. Rank and select supports implement `Serialize` but not `Deserialize`, because their
+//! serialized form does not contain the supported bit vector. Load them with `deserialize_seed` and serde's
+//! `DeserializeSeed`, or with `from_bytes`.
+
+// Makes the renamed dependency available as `serde`. Derives also need `#[serde(crate = "serde")]`.
+extern crate serde_crate as serde;
 
 mod backend;
+mod error;
 mod interface;
 mod meta;
 
-pub use crate::backend::build;
+pub use crate::backend::sdsl_c::init_with_library;
+pub use crate::backend::sdsl_c::manifest::{available_instantiations, Instantiation};
+pub use crate::backend::{build, build_with, Build, Spec};
+pub use crate::error::{Error, IoSource, Result};
 pub use crate::interface::crate_export::*;
@@ -28,10 +28,13 @@ impl common::Meta for RrrVectorMeta {
 
         let c_code = self.c_code(&parameters_c_code)?;
         let io_specifications = common::io::file_specifications(&c_code, None, &id)?;
+        let extension_specifications =
+            common::extension::file_specifications("rrr_vector.cpp", &c_code, id)?;
 
         let mut specifications = vec![source, header];
         specifications.extend(bit_vector_specs);
         specifications.extend(io_specifications);
+        specifications.extend(extension_specifications);
         Ok(specifications)
     }
 }
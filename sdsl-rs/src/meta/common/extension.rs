@@ -0,0 +1,79 @@
+use crate::meta::common;
+use anyhow::Result;
+
+/// Returns the specifications of an extension source and of the files which it depends on.
+///
+/// Extension sources define the C functions which sdsl-rs adds to the sdsl-c template. They include a shared header
+/// and report C++ exceptions through the last-error slot of `error.cpp`.
+/// # Arguments
+/// * `template_file_name` - Name of the extension source within the `extensions` directory.
+/// * `struct_c_code` - C++ code of the structure which the functions operate on.
+/// * `id` - Instance ID appended to the function names.
+pub fn file_specifications(
+    template_file_name: &str,
+    struct_c_code: &str,
+    id: &str,
+) -> Result<Vec<common::FileSpecification>> {
+    let header = get_header_specification();
+    let source = get_source_specification(template_file_name, struct_c_code, id)?;
+    Ok(vec![header, get_error_specification(), source])
+}
+
+/// Returns the specifications of the wavelet tree extension source and of the files which it depends on.
+/// # Arguments
+/// * `wavelet_tree_name` - Prefix of the function names, for example `wt_int`.
+/// * `struct_c_code` - C++ code of the wavelet tree.
+/// * `id` - Instance ID appended to the function names.
+pub fn wavelet_tree_file_specifications(
+    wavelet_tree_name: &str,
+    struct_c_code: &str,
+    id: &str,
+) -> Result<Vec<common::FileSpecification>> {
+    let header = get_header_specification();
+    let mut source = get_source_specification("wavelet_tree.cpp", struct_c_code, id)?;
+    source.replacements.insert(
+        "#define WAVELET_TREE wt_int".to_string(),
+        format!("#define WAVELET_TREE {}", wavelet_tree_name),
+    );
+    Ok(vec![header, get_error_specification(), source])
+}
+
+fn get_header_specification() -> common::FileSpecification {
+    let file_name = std::path::PathBuf::from("extensions/extension.hpp");
+    common::FileSpecification {
+        replacements: maplit::btreemap! {},
+        template_file_name: file_name.clone(),
+        target_file_name: file_name.clone(),
+        c_file_type: common::CFileType::Hpp,
+    }
+}
+
+/// The last-error slot is shared by all structures, so its source is not instantiated per ID.
+fn get_error_specification() -> common::FileSpecification {
+    let file_name = std::path::PathBuf::from("extensions/error.cpp");
+    common::FileSpecification {
+        replacements: maplit::btreemap! {},
+        template_file_name: file_name.clone(),
+        target_file_name: file_name.clone(),
+        c_file_type: common::CFileType::Cpp,
+    }
+}
+
+fn get_source_specification(
+    template_file_name: &str,
+    struct_c_code: &str,
+    id: &str,
+) -> Result<common::FileSpecification> {
+    let template_file_name = std::path::PathBuf::from("extensions").join(template_file_name);
+    let target_file_name = common::get_target_file_name(&template_file_name, id)?;
+
+    Ok(common::FileSpecification {
+        replacements: maplit::btreemap! {
+            "#define EXTENSION_ID _id".to_string() => format!("#define EXTENSION_ID _{}", id),
+            "#define STRUCTURE sdsl::int_vector<0>".to_string() => format!("#define STRUCTURE {}", struct_c_code),
+        },
+        template_file_name: template_file_name.clone(),
+        target_file_name: target_file_name.clone(),
+        c_file_type: common::CFileType::Cpp,
+    })
+}
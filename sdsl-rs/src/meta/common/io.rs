@@ -8,7 +8,13 @@ pub fn file_specifications(
 ) -> Result<Vec<common::FileSpecification>> {
     let header = get_header_specification(&struct_c_code, int_vector_struct_c_code, &id)?;
     let source = get_source_specification(&header, &id)?;
-    Ok(vec![header, source])
+    let mut specifications = vec![header, source];
+    specifications.extend(common::extension::file_specifications(
+        "io.cpp",
+        struct_c_code,
+        id,
+    )?);
+    Ok(specifications)
 }
 
 fn get_header_specification(
@@ -1,17 +1,20 @@
 use anyhow::{format_err, Result};
 
 pub mod bit_patterns;
+pub mod extension;
 pub mod io;
 pub mod params;
 pub mod util;
 
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(crate = "serde")]
 pub enum CFileType {
     Cpp,
     Hpp,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(crate = "serde")]
 pub struct FileSpecification {
     pub replacements: std::collections::BTreeMap<String, String>,
     pub template_file_name: std::path::PathBuf,
@@ -7,7 +7,13 @@ pub fn file_specifications(
 ) -> Result<Vec<common::FileSpecification>> {
     let header = get_header_specification(&struct_c_code, &id)?;
     let source = get_source_specification(&header, &id)?;
-    Ok(vec![header, source])
+    let mut specifications = vec![header, source];
+    specifications.extend(common::extension::file_specifications(
+        "util.cpp",
+        struct_c_code,
+        id,
+    )?);
+    Ok(specifications)
 }
 
 fn get_header_specification(struct_c_code: &str, id: &str) -> Result<common::FileSpecification> {
@@ -27,10 +27,13 @@ impl common::Meta for IntVectorMeta {
 
         let util_specifications = common::util::file_specifications(&c_code, &id)?;
         let io_specifications = common::io::file_specifications(&c_code, Some(&c_code), &id)?;
+        let extension_specifications =
+            common::extension::file_specifications("int_vector.cpp", &c_code, id)?;
 
         let mut specifications = vec![source, header];
         specifications.extend(util_specifications);
         specifications.extend(io_specifications);
+        specifications.extend(extension_specifications);
         Ok(specifications)
     }
 }
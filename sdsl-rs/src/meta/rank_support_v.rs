@@ -31,9 +31,12 @@ impl common::Meta for RankSupportVMeta {
 
         let c_code = self.c_code(&parameters_c_code)?;
         let io_specifications = common::io::file_specifications(&c_code, None, &id)?;
+        let extension_specifications =
+            common::extension::file_specifications("rank_support_v.cpp", &c_code, id)?;
 
         let mut specifications = vec![source, header];
         specifications.extend(io_specifications);
+        specifications.extend(extension_specifications);
         Ok(specifications)
     }
 }
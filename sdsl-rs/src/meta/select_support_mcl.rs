@@ -31,9 +31,12 @@ impl common::Meta for SelectSupportMclMeta {
 
         let c_code = self.c_code(&parameters_c_code)?;
         let io_specifications = common::io::file_specifications(&c_code, None, &id)?;
+        let extension_specifications =
+            common::extension::file_specifications("select_support_mcl.cpp", &c_code, id)?;
 
         let mut specifications = vec![source, header];
         specifications.extend(io_specifications);
+        specifications.extend(extension_specifications);
         Ok(specifications)
     }
 }
@@ -100,7 +103,7 @@ fn get_header_replacements(
 
 impl common::Path for SelectSupportMclMeta {
     fn path(&self) -> String {
-        "sdsl::rank_supports::RankSupportV".to_string()
+        "sdsl::select_supports::SelectSupportMcl".to_string()
     }
 }
 
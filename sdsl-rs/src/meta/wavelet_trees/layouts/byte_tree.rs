@@ -79,7 +79,7 @@ impl common::Parameters for ByteTreeMeta {
     }
 
     fn parameters_default_c_code(&self) -> Result<Vec<String>> {
-        DefaultInterfaceType::parameters_c_code()
+        DefaultInterfaceType::parameters_c_code().map_err(anyhow::Error::from)
     }
 
     fn parameters_default_meta(&self) -> &Vec<Box<dyn common::Meta>> {
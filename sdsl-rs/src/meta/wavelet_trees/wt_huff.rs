@@ -59,10 +59,13 @@ impl common::Meta for WtHuffMeta {
 
         let c_code = self.c_code(&parameters_c_code)?;
         let io_specifications = common::io::file_specifications(&c_code, None, &id)?;
+        let extension_specifications =
+            common::extension::wavelet_tree_file_specifications("wt_huff", &c_code, id)?;
 
         let mut specifications = vec![source, header];
         specifications.extend(bit_vector_specs);
         specifications.extend(io_specifications);
+        specifications.extend(extension_specifications);
 
         let tree_strategy_file_specs = parameters_file_specs.last().ok_or(format_err!(
             "Parameters file specs empty. Expected at least one element."
@@ -187,7 +190,7 @@ impl common::Parameters for WtHuffMeta {
 
     fn parameters_default_c_code(&self) -> Result<Vec<String>> {
         use crate::interface::common::Code;
-        DefaultInterfaceType::parameters_c_code()
+        DefaultInterfaceType::parameters_c_code().map_err(anyhow::Error::from)
     }
 
     fn parameters_default_meta(&self) -> &Vec<Box<dyn common::Meta>> {